@@ -3,7 +3,7 @@ use kalendarium::RomanNumeral;
 
 #[derive(Parser)]
 struct Cli {
-    number: u32,
+    number: u64,
 }
 
 fn main() {
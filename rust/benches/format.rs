@@ -0,0 +1,220 @@
+//! Benchmarks for writing Roman numerals
+//!
+//! ``legacy`` is the recursive, allocating ``arabic_to_roman`` that ``RomanNumeral`` used before
+//! the table-driven writer, kept here as the baseline to compare against.
+
+use core::fmt::Write;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use kalendarium::{NumeralStyle, RomanNumeral};
+
+fn bench_format(c: &mut Criterion) {
+    let numbers: Vec<u32> = (0..4_999_999).step_by(4_999).collect();
+    let numerals: Vec<RomanNumeral> = numbers
+        .iter()
+        .map(|&number| RomanNumeral::new(u64::from(number)).unwrap())
+        .collect();
+
+    let mut group = c.benchmark_group("format");
+    group.bench_function("legacy", |b| {
+        b.iter(|| {
+            for &number in &numbers {
+                black_box(legacy::to_uppercase(black_box(number)));
+            }
+        })
+    });
+    group.bench_function("to_uppercase", |b| {
+        b.iter(|| {
+            for numeral in &numerals {
+                black_box(black_box(numeral).to_uppercase());
+            }
+        })
+    });
+    group.bench_function("write_with", |b| {
+        let mut out = String::with_capacity(128);
+        b.iter(|| {
+            for numeral in &numerals {
+                out.clear();
+                black_box(numeral)
+                    .write_with(&NumeralStyle::UPPERCASE, &mut out)
+                    .unwrap();
+                black_box(&out);
+            }
+        })
+    });
+    group.bench_function("format_into", |b| {
+        let mut buf = [0_u8; 128];
+        b.iter(|| {
+            for numeral in &numerals {
+                let roman = black_box(numeral)
+                    .format_into(&NumeralStyle::UPPERCASE, &mut buf)
+                    .unwrap();
+                black_box(roman);
+            }
+        })
+    });
+    group.bench_function("display", |b| {
+        let mut out = String::with_capacity(128);
+        b.iter(|| {
+            for numeral in &numerals {
+                out.clear();
+                write!(out, "{}", black_box(numeral)).unwrap();
+                black_box(&out);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_format);
+criterion_main!(benches);
+
+mod legacy {
+    struct Arabic2RomanStruct<'a> {
+        arabic: u32,
+        u_latin: &'a str,
+        l_latin: &'a str,
+    }
+
+    static NUMERI: [Arabic2RomanStruct; 13] = [
+        Arabic2RomanStruct {
+            arabic: 1000000,
+            u_latin: "M̅",
+            l_latin: "m̅",
+        },
+        Arabic2RomanStruct {
+            arabic: 500000,
+            u_latin: "D̅",
+            l_latin: "d̅",
+        },
+        Arabic2RomanStruct {
+            arabic: 100000,
+            u_latin: "C̅",
+            l_latin: "c̅",
+        },
+        Arabic2RomanStruct {
+            arabic: 50000,
+            u_latin: "L̅",
+            l_latin: "l̅",
+        },
+        Arabic2RomanStruct {
+            arabic: 10000,
+            u_latin: "X̅",
+            l_latin: "x̅",
+        },
+        Arabic2RomanStruct {
+            arabic: 5000,
+            u_latin: "V̅",
+            l_latin: "u̅",
+        },
+        Arabic2RomanStruct {
+            arabic: 1000,
+            u_latin: "M",
+            l_latin: "m",
+        },
+        Arabic2RomanStruct {
+            arabic: 500,
+            u_latin: "D",
+            l_latin: "d",
+        },
+        Arabic2RomanStruct {
+            arabic: 100,
+            u_latin: "C",
+            l_latin: "c",
+        },
+        Arabic2RomanStruct {
+            arabic: 50,
+            u_latin: "L",
+            l_latin: "l",
+        },
+        Arabic2RomanStruct {
+            arabic: 10,
+            u_latin: "X",
+            l_latin: "x",
+        },
+        Arabic2RomanStruct {
+            arabic: 5,
+            u_latin: "V",
+            l_latin: "u",
+        },
+        Arabic2RomanStruct {
+            arabic: 1,
+            u_latin: "I",
+            l_latin: "i",
+        },
+    ];
+
+    pub fn to_uppercase(num: u32) -> String {
+        if num == 0 {
+            "N".to_string()
+        } else {
+            arabic_to_roman(num, true)
+        }
+    }
+
+    fn repeat(total: u8, character: &str) -> String {
+        let mut count = total;
+        let mut out = String::new();
+        while count > 0 {
+            out.push_str(character);
+            count -= 1;
+        }
+        out
+    }
+
+    fn arabic_to_roman(num: u32, uppercase: bool) -> String {
+        let mut out: String = String::new();
+        if num != 0 {
+            for (index, basis) in NUMERI.iter().enumerate() {
+                let arabic = num / basis.arabic;
+                if arabic > 0 {
+                    let offset = if basis.arabic.to_string().contains("5") {
+                        1
+                    } else {
+                        0
+                    };
+                    if index > 0 && num >= NUMERI[index - 1].arabic - NUMERI[index + offset].arabic
+                    {
+                        if (4000..10000).contains(&num) {
+                            if uppercase {
+                                out.push_str("I̅");
+                            } else {
+                                out.push_str("i̅");
+                            }
+                        } else if uppercase {
+                            out.push_str(NUMERI[index + offset].u_latin);
+                        } else {
+                            out.push_str(NUMERI[index + offset].l_latin);
+                        }
+                        if uppercase {
+                            out.push_str(NUMERI[index - 1].u_latin);
+                        } else {
+                            out.push_str(NUMERI[index - 1].l_latin);
+                        }
+                        out.push_str(&arabic_to_roman(
+                            (num - (NUMERI[index - 1].arabic - NUMERI[index + offset].arabic))
+                                % basis.arabic,
+                            uppercase,
+                        ));
+                    } else {
+                        if uppercase {
+                            out.push_str(&repeat(u8::try_from(arabic).unwrap(), basis.u_latin));
+                        } else {
+                            out.push_str(&repeat(u8::try_from(arabic).unwrap(), basis.l_latin));
+                        }
+                        out.push_str(&arabic_to_roman(
+                            u32::try_from(
+                                (isize::try_from(num).unwrap()
+                                    - isize::try_from(basis.arabic * 10).unwrap())
+                                .rem_euclid(isize::try_from(basis.arabic).unwrap()),
+                            )
+                            .unwrap(),
+                            uppercase,
+                        ));
+                    }
+                    break;
+                }
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,98 @@
+//! # kalendarium additive numerals
+//!
+//! The subtractive forms IV and IX only became the norm in modern times. Roman inscriptions just
+//! as often write fours and nines additively, e.g. XIIII and MDCCCC for 14 and 1900, and clock
+//! faces still write IIII while keeping IX. Which fours and nines are written additively is chosen
+//! per decimal place with ``AdditiveForms``.
+//!
+//! https://en.wikipedia.org/wiki/Roman_numerals#Other_additive_forms
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+#[cfg(feature = "std")]
+use crate::NumeralStyle;
+use crate::RomanNumeral;
+
+/// Which fours and nines are written additively, chosen per decimal place
+///
+/// Place 0 is the units, place 1 the tens, and so on up to place 9, the billions. Billions are
+/// always additive, because there is no symbol for five billion.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct AdditiveForms {
+    fours: u16,
+    nines: u16,
+}
+
+impl AdditiveForms {
+    /// Every four and nine is subtractive, e.g. XIV and MCM
+    pub const NONE: AdditiveForms = AdditiveForms { fours: 0, nines: 0 };
+    /// Every four and nine is additive, as on classical inscriptions, e.g. XIIII and MDCCCC
+    pub const ALL: AdditiveForms = AdditiveForms {
+        fours: 0x3ff,
+        nines: 0x3ff,
+    };
+    /// Only the units four is additive, as on clock faces, e.g. IIII but IX
+    pub const CLOCK: AdditiveForms = AdditiveForms { fours: 1, nines: 0 };
+
+    /// Writes the four of ``place`` additively, e.g. ``fours(1)`` gives XXXX for 40.
+    #[must_use]
+    pub const fn fours(self, place: u32) -> Self {
+        AdditiveForms {
+            fours: self.fours | Self::bit(place),
+            nines: self.nines,
+        }
+    }
+
+    /// Writes the nine of ``place`` additively, e.g. ``nines(2)`` gives DCCCC for 900.
+    #[must_use]
+    pub const fn nines(self, place: u32) -> Self {
+        AdditiveForms {
+            fours: self.fours,
+            nines: self.nines | Self::bit(place),
+        }
+    }
+
+    /// Writes both the four and the nine of ``place`` additively.
+    #[must_use]
+    pub const fn place(self, place: u32) -> Self {
+        self.fours(place).nines(place)
+    }
+
+    /// Whether ``digit`` at ``place`` is written additively.
+    #[must_use]
+    pub const fn is_additive(self, place: u32, digit: u32) -> bool {
+        match digit {
+            4 => self.fours & Self::bit(place) != 0,
+            9 => self.nines & Self::bit(place) != 0,
+            _ => true,
+        }
+    }
+
+    /// Helper function for the flag of a decimal place
+    const fn bit(place: u32) -> u16 {
+        if place < 10 { 1 << place } else { 0 }
+    }
+}
+
+impl RomanNumeral {
+    /// Converts a ``RomanNumeral`` to an uppercase string, writing the chosen fours and nines
+    /// additively.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: RomanNumeral = RomanNumeral::new(1914)?;
+    ///    assert_eq!("MDCCCCXIIII", answer.to_additive(AdditiveForms::ALL));
+    ///    assert_eq!("MCMXIIII", answer.to_additive(AdditiveForms::CLOCK));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_additive(self, forms: AdditiveForms) -> String {
+        let style = NumeralStyle::new().additive(forms);
+        self.format_with(&style).unwrap_or_default()
+    }
+}
@@ -0,0 +1,103 @@
+//! # kalendarium apostrophus notation
+//!
+//! Before the vinculum became the usual way of writing large numbers, and again in Renaissance and
+//! early-modern printing, thousands were written with the apostrophus: an I enclosed by Cs and
+//! reversed Cs (Ↄ). Each additional pair multiplies the value by ten, so CIↃ is 1000, CCIↃↃ is
+//! 10,000 and CCCIↃↃↃ is 100,000. Dropping the leading Cs halves the value, so IↃ is 500 and IↃↃ
+//! is 5000.
+//!
+//! Because a C written before an apostrophic symbol would read as part of it, numbers of a hundred
+//! and more are always written additively, e.g. CIↃIↃCCCC for 1900.
+//!
+//! https://en.wikipedia.org/wiki/Roman_numerals#Apostrophus
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+#[cfg(feature = "std")]
+use crate::LargeNumerals;
+use crate::{NumeralStyle, RomanNumeral};
+use core::fmt::{self, Write};
+
+/// The ways of writing the apostrophus
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub enum Apostrophus {
+    /// Letters and the reversed C (U+2183), e.g. CIↃIↃCXXV for 1625
+    #[default]
+    Unicode,
+    /// The precomposed symbols ↀ ↁ ↂ ↇ ↈ (U+2180–U+2188) where they exist, e.g. ↀIↃCXXV
+    Ligatures,
+    /// ASCII letters with a closing parenthesis for the reversed C, e.g. CI)I)CXXV
+    Ascii,
+}
+
+impl RomanNumeral {
+    /// Converts a ``RomanNumeral`` to an uppercase string using the apostrophus for 500 and up.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: RomanNumeral = RomanNumeral::new(1625)?;
+    ///    assert_eq!("CIↃIↃCXXV", year.to_apostrophus(Apostrophus::Unicode));
+    ///    assert_eq!("ↀIↃCXXV", year.to_apostrophus(Apostrophus::Ligatures));
+    ///    assert_eq!("CI)I)CXXV", year.to_apostrophus(Apostrophus::Ascii));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_apostrophus(self, form: Apostrophus) -> String {
+        let style = NumeralStyle::new().large(LargeNumerals::Apostrophus(form));
+        self.format_with(&style).unwrap_or_default()
+    }
+
+    /// Helper function to write the symbol of one or five at a decimal place of a hundred or
+    /// more, e.g. C, CCIↃↃ or IↃↃ
+    pub(crate) fn write_apostrophus<W: Write>(
+        out: &mut W,
+        place: usize,
+        five: bool,
+        form: Apostrophus,
+        style: &NumeralStyle,
+    ) -> fmt::Result {
+        let ligature = match (form, place, five) {
+            (Apostrophus::Ligatures, 3, false) => Some('ↀ'),
+            (Apostrophus::Ligatures, 3, true) => Some('ↁ'),
+            (Apostrophus::Ligatures, 4, false) => Some('ↂ'),
+            (Apostrophus::Ligatures, 4, true) => Some('ↇ'),
+            (Apostrophus::Ligatures, 5, false) => Some('ↈ'),
+            _ => None,
+        };
+        if let Some(symbol) = ligature {
+            return out.write_char(symbol);
+        }
+
+        let (i, c, reversed) = match (form, style.is_upper(), style.is_number_forms()) {
+            (Apostrophus::Ascii, true, _) => ('I', 'C', ')'),
+            (Apostrophus::Ascii, false, _) => ('i', 'c', ')'),
+            (_, true, true) => ('Ⅰ', 'Ⅽ', 'Ↄ'),
+            (_, false, true) => ('ⅰ', 'ⅽ', 'ↄ'),
+            (_, true, false) => ('I', 'C', 'Ↄ'),
+            (_, false, false) => ('i', 'c', 'ↄ'),
+        };
+        if five {
+            out.write_char(i)?;
+            for _ in 1..place {
+                out.write_char(reversed)?;
+            }
+        } else if place == 2 {
+            out.write_char(c)?;
+        } else {
+            for _ in 2..place {
+                out.write_char(c)?;
+            }
+            out.write_char(i)?;
+            for _ in 2..place {
+                out.write_char(reversed)?;
+            }
+        }
+        Ok(())
+    }
+}
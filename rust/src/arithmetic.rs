@@ -0,0 +1,258 @@
+//! # kalendarium Roman numeral arithmetic
+//!
+//! The operators on ``RomanNumeral`` return a ``Result``, because the sum or product of two
+//! numerals can be larger than ``MAX``, and a difference can fall below zero. The ``checked_*``
+//! methods return an ``Option`` instead, and the ``saturating_*`` methods stop at ``MIN`` and
+//! ``MAX``. Sums and products of iterators are collected into a ``Result`` in the same way.
+//!
+//! With the ``num-traits`` feature, ``RomanNumeral`` also implements ``Bounded``,
+//! ``ToPrimitive``, ``FromPrimitive`` and ``NumCast``.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{MAX, MIN, OutOfRangeError, Result, RomanNumeral};
+use core::iter::{Product, Sum};
+use core::ops::{Add, Bound, Div, Mul, RangeBounds, Rem, Sub};
+
+impl RomanNumeral {
+    /// Adds two numerals, returning ``None`` if the sum is larger than ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let total: Option<RomanNumeral> = RomanNumeral::new(40)?.checked_add(RomanNumeral::new(2)?);
+    ///    assert_eq!(total, Some(RomanNumeral::new(42)?));
+    ///
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::checked(self.0.checked_add(rhs.0))
+    }
+
+    /// Subtracts ``rhs``, returning ``None`` if it is larger than ``self``.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::checked(self.0.checked_sub(rhs.0))
+    }
+
+    /// Multiplies two numerals, returning ``None`` if the product is larger than ``MAX``.
+    #[must_use]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked(self.0.checked_mul(rhs.0))
+    }
+
+    /// Divides by ``rhs``, rounding down, returning ``None`` if ``rhs`` is zero.
+    #[must_use]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::checked(self.0.checked_div(rhs.0))
+    }
+
+    /// The remainder of dividing by ``rhs``, returning ``None`` if ``rhs`` is zero.
+    #[must_use]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        Self::checked(self.0.checked_rem(rhs.0))
+    }
+
+    /// Adds two numerals, stopping at ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let total: RomanNumeral = RomanNumeral::new(MAX)?.saturating_add(RomanNumeral::new(1)?);
+    ///    assert_eq!(total.as_u64(), MAX);
+    ///
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self::saturating(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts ``rhs``, stopping at zero (N).
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        RomanNumeral(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Multiplies two numerals, stopping at ``MAX``.
+    #[must_use]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        Self::saturating(self.0.saturating_mul(rhs.0))
+    }
+
+    /// Iterates over every numeral in ``range``, in order.
+    ///
+    /// An unbounded start begins at ``MIN``, and an unbounded end stops at ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let (i, x) = (RomanNumeral::new(1)?, RomanNumeral::new(10)?);
+    ///    let pages: Vec<String> = RomanNumeral::range(i..=x).map(|page| page.to_lowercase()).collect();
+    ///    assert_eq!(pages[3], "iu");
+    ///    assert_eq!(pages.len(), 10);
+    ///
+    pub fn range<R: RangeBounds<RomanNumeral>>(
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = RomanNumeral> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => start.0,
+            Bound::Excluded(start) => start.0.saturating_add(1),
+            Bound::Unbounded => MIN,
+        };
+        // Every numeral is at most MAX, so one past the end cannot overflow
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.0 + 1,
+            Bound::Excluded(end) => end.0,
+            Bound::Unbounded => MAX + 1,
+        };
+        (start..end).map(RomanNumeral)
+    }
+
+    /// Helper function to keep a checked result in range
+    const fn checked(value: Option<u64>) -> Option<Self> {
+        match value {
+            Some(value) if value <= MAX => Some(RomanNumeral(value)),
+            _ => None,
+        }
+    }
+
+    /// Helper function to clamp a result to ``MAX``
+    const fn saturating(value: u64) -> Self {
+        if value <= MAX {
+            RomanNumeral(value)
+        } else {
+            RomanNumeral(MAX)
+        }
+    }
+}
+
+impl Add for RomanNumeral {
+    type Output = Result<RomanNumeral, OutOfRangeError>;
+
+    /// Adds two numerals.
+    ///
+    /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).ok_or(OutOfRangeError)
+    }
+}
+
+impl Sub for RomanNumeral {
+    type Output = Result<RomanNumeral, OutOfRangeError>;
+
+    /// Subtracts two numerals.
+    ///
+    /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).ok_or(OutOfRangeError)
+    }
+}
+
+impl Mul for RomanNumeral {
+    type Output = Result<RomanNumeral, OutOfRangeError>;
+
+    /// Multiplies two numerals.
+    ///
+    /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).ok_or(OutOfRangeError)
+    }
+}
+
+impl Div for RomanNumeral {
+    type Output = Result<RomanNumeral, OutOfRangeError>;
+
+    /// Divides two numerals, rounding down.
+    ///
+    /// Returns ``RomanNumeral`` or ``OutOfRangeError`` when dividing by zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs).ok_or(OutOfRangeError)
+    }
+}
+
+impl Rem for RomanNumeral {
+    type Output = Result<RomanNumeral, OutOfRangeError>;
+
+    /// The remainder of dividing two numerals.
+    ///
+    /// Returns ``RomanNumeral`` or ``OutOfRangeError`` when dividing by zero.
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_rem(rhs).ok_or(OutOfRangeError)
+    }
+}
+
+impl Sum<RomanNumeral> for Result<RomanNumeral, OutOfRangeError> {
+    /// Adds up the numerals, returning ``OutOfRangeError`` if the sum is larger than ``MAX``.
+    fn sum<I: Iterator<Item = RomanNumeral>>(mut iter: I) -> Self {
+        iter.try_fold(RomanNumeral(0), |total, num| total + num)
+    }
+}
+
+impl<'a> Sum<&'a RomanNumeral> for Result<RomanNumeral, OutOfRangeError> {
+    /// Adds up the numerals, returning ``OutOfRangeError`` if the sum is larger than ``MAX``.
+    fn sum<I: Iterator<Item = &'a RomanNumeral>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product<RomanNumeral> for Result<RomanNumeral, OutOfRangeError> {
+    /// Multiplies the numerals, returning ``OutOfRangeError`` if the product is larger than
+    /// ``MAX``.
+    fn product<I: Iterator<Item = RomanNumeral>>(mut iter: I) -> Self {
+        iter.try_fold(RomanNumeral(1), |total, num| total * num)
+    }
+}
+
+impl<'a> Product<&'a RomanNumeral> for Result<RomanNumeral, OutOfRangeError> {
+    /// Multiplies the numerals, returning ``OutOfRangeError`` if the product is larger than
+    /// ``MAX``.
+    fn product<I: Iterator<Item = &'a RomanNumeral>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Bounded for RomanNumeral {
+    fn min_value() -> Self {
+        RomanNumeral(MIN)
+    }
+
+    fn max_value() -> Self {
+        RomanNumeral(MAX)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::ToPrimitive for RomanNumeral {
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self.0).ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.0)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::FromPrimitive for RomanNumeral {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::new(n).ok()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::NumCast for RomanNumeral {
+    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
+        n.to_u64().and_then(|n| Self::new(n).ok())
+    }
+}
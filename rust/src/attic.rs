@@ -0,0 +1,439 @@
+//! # kalendarium Attic numerals
+//!
+//! The acrophonic numerals of Athenian inscriptions, where the numerals for five, ten, a hundred,
+//! a thousand and ten thousand are the first letters of their names: Π (πέντε), Δ (δέκα),
+//! Η (ἑκατόν), Χ (χίλιοι) and Μ (μύριοι). The fifties are written with a small numeral inside a
+//! Π, such as 𐅄 for 50 and 𐅆 for 5000, from the Ancient Greek Numbers block (U+10140). Numerals
+//! are written additively, with at most four of each sign, e.g. ΧΗΗΗ𐅄ΔΠΙΙ for 1367. Values
+//! from 1 to 99,999 are supported, as the system has no zero.
+//!
+//! Sums of money were written with the signs of their unit: the drachma sign (𐅂) for the ones
+//! of a sum in drachmas, and the talent signs, from Τ for one talent to 𐅎 for 5000 talents. The
+//! parser also accepts the regional signs of Thespiae, Troezen, Hermione, Messene, Epidaurus,
+//! Carystus and Naxos for the same values, and the Thespian signs for two, thirty and three
+//! hundred in place of two or three of the sign below them.
+//!
+//! https://en.wikipedia.org/wiki/Attic_numerals
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{OutOfRangeError, ParseErrorKind, ParseNumeralError, Result};
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// An Attic acrophonic numeral
+///
+/// Values from 1 to 99,999 are supported
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct AtticNumeral(pub u32);
+
+/// The unit counted by an ``AtticNumeral``, which selects its signs
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum AtticUnit {
+    /// A plain number, e.g. ΔΠΙΙ for 17
+    #[default]
+    Number,
+    /// A sum in drachmas, with the drachma sign for the ones, e.g. ΔΠ𐅂𐅂 for 17 drachmas
+    Drachmas,
+    /// A sum in talents, with the talent signs, e.g. 𐅉𐅈ΤΤ for 17 talents. There are no talent
+    /// signs for ten thousand and more, so those are written with Μ and 𐅇
+    Talents,
+}
+
+impl AtticNumeral {
+    /// The value of the smallest Attic numeral
+    pub const MIN: u32 = 1;
+    /// The value of the largest Attic numeral
+    pub const MAX: u32 = 99_999;
+
+    /// Creates an ``AtticNumeral`` for any value in range.
+    /// Requires ``value`` to be between 1 and 99,999, as there is no Attic zero.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: AtticNumeral = AtticNumeral::new(42)?;
+    ///    assert_eq!("ΔΔΔΔΙΙ", answer.to_string());
+    ///
+    pub const fn new(value: u32) -> Result<Self, OutOfRangeError> {
+        if value >= Self::MIN && value <= Self::MAX {
+            Ok(AtticNumeral(value))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Return the value of this ``AtticNumeral`` as a ``u32``.
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Converts an ``AtticNumeral`` to a string written with the signs of ``unit``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let sum: AtticNumeral = AtticNumeral::new(1367)?;
+    ///    assert_eq!("ΧΗΗΗ𐅄ΔΠ𐅂𐅂", sum.format_with(AtticUnit::Drachmas));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format_with(self, unit: AtticUnit) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_with(unit, &mut out);
+        out
+    }
+
+    /// Writes an ``AtticNumeral`` with the signs of ``unit`` to any ``fmt::Write``, without
+    /// allocating.
+    pub fn write_with<W: Write>(&self, unit: AtticUnit, out: &mut W) -> fmt::Result {
+        let signs = match unit {
+            AtticUnit::Number => &NUMBER,
+            AtticUnit::Drachmas => &DRACHMAS,
+            AtticUnit::Talents => &TALENTS,
+        };
+        let mut rest = self.0;
+        for (&value, &sign) in VALUES.iter().zip(signs) {
+            while rest >= value {
+                out.write_char(sign)?;
+                rest -= value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses an ``AtticNumeral`` from a string.
+    ///
+    /// The signs must be in canonical order, from the largest to the smallest, with each value
+    /// written with the fewest signs. A sum in drachmas or talents is read as the number of
+    /// drachmas or talents, and must not mix the two units.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let sum: AtticNumeral = AtticNumeral::parse("ΧΗΗΗ𐅄ΔΠΙΙ")?;
+    ///    assert_eq!(sum.as_u32(), 1367);
+    ///
+    pub fn parse(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse_with_unit(s).map(|(num, _)| num)
+    }
+
+    /// Parses an ``AtticNumeral`` from a string, along with the unit of its signs.
+    ///
+    /// The signs shared by numbers and drachmas cannot tell them apart, so a sum in drachmas
+    /// without any ones, such as ΔΠ, is read as a ``AtticUnit::Number``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let (sum, unit) = AtticNumeral::parse_with_unit("𐅉𐅈ΤΤ")?;
+    ///    assert_eq!((sum.as_u32(), unit), (17, AtticUnit::Talents));
+    ///
+    pub fn parse_with_unit(s: &str) -> Result<(Self, AtticUnit), ParseNumeralError> {
+        if s.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        let mut value: u32 = 0;
+        let mut unit = AtticUnit::Number;
+        for (position, character) in s.char_indices() {
+            match Self::sign_value(character) {
+                Some((sign, sign_unit)) => {
+                    value = value.saturating_add(sign);
+                    match sign_unit {
+                        Some(AtticUnit::Talents) => unit = AtticUnit::Talents,
+                        Some(AtticUnit::Drachmas) if unit == AtticUnit::Number => {
+                            unit = AtticUnit::Drachmas;
+                        }
+                        _ => (),
+                    }
+                }
+                None => {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::InvalidCharacter,
+                        position,
+                    ));
+                }
+            }
+        }
+        if value > Self::MAX {
+            return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0));
+        }
+
+        // Each sign must be the largest which fits in what is left, or stand for up to four of
+        // them, and belong to the unit
+        let mut rest = value;
+        for (position, character) in s.char_indices() {
+            let (sign, sign_unit) = Self::sign_value(character).unwrap_or_default();
+            let expected = VALUES.iter().find(|&&value| value <= rest);
+            let same_unit = match (unit, sign_unit) {
+                (AtticUnit::Talents, None) => sign >= MYRIAD,
+                (_, None) => true,
+                (unit, Some(sign_unit)) => unit == sign_unit,
+            };
+            let repeated = expected.is_some_and(|&expected| {
+                sign > expected && sign % expected == 0 && sign < 5 * expected
+            });
+            if (expected != Some(&sign) && !repeated) || !same_unit {
+                return Err(ParseNumeralError::new(
+                    ParseErrorKind::NonCanonical,
+                    position,
+                ));
+            }
+            rest -= sign;
+        }
+        Ok((AtticNumeral(value), unit))
+    }
+
+    /// Helper function returning the value of a sign, and the unit it is only used for. The
+    /// number signs from 5 to 5000 are shared with drachmas, and Μ and 𐅇 with all units.
+    fn sign_value(character: char) -> Option<(u32, Option<AtticUnit>)> {
+        let number = Some(AtticUnit::Number);
+        let talents = Some(AtticUnit::Talents);
+        let sign = match character {
+            // Thespian and Hermionian one
+            'Ι' | '\u{10159}' | '\u{1015a}' => (1, number),
+            // Thespian two
+            '\u{1015c}' => (2, number),
+            '\u{10142}' => (1, Some(AtticUnit::Drachmas)),
+            // The Attic five and the Troezenian five
+            'Π' | '\u{10143}' | '\u{1015f}' => (5, None),
+            // Troezenian, Hermionian, Messenian and Thespian ten
+            'Δ' | '\u{10160}'..='\u{10164}' => (10, None),
+            // Thespian thirty
+            '\u{10165}' => (30, None),
+            // Troezenian, Hermionian and Thespian fifty
+            '\u{10144}' | '\u{10166}'..='\u{10169}' => (50, None),
+            // Thespian hundred
+            'Η' | '\u{1016a}' => (100, None),
+            // Thespian three hundred
+            '\u{1016b}' => (300, None),
+            // Epidaurean, Troezenian, Thespian, Carystian and Naxian five hundred
+            '\u{10145}' | '\u{1016c}'..='\u{10170}' => (500, None),
+            // Thespian thousand
+            'Χ' | '\u{10171}' => (1000, None),
+            // Thespian five thousand
+            '\u{10146}' | '\u{10172}' => (5000, None),
+            'Μ' => (MYRIAD, None),
+            '\u{10147}' => (50_000, None),
+            'Τ' => (1, talents),
+            '\u{10148}' => (5, talents),
+            '\u{10149}' => (10, talents),
+            '\u{1014a}' => (50, talents),
+            '\u{1014b}' => (100, talents),
+            '\u{1014c}' => (500, talents),
+            '\u{1014d}' => (1000, talents),
+            '\u{1014e}' => (5000, talents),
+            _ => return None,
+        };
+        Some(sign)
+    }
+}
+
+impl fmt::Display for AtticNumeral {
+    /// Converts an ``AtticNumeral`` to a string of plain number signs.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: AtticNumeral = AtticNumeral::new(1984)?;
+    ///    assert_eq!("Χ𐅅ΗΗΗΗ𐅄ΔΔΔΙΙΙΙ", year.to_string());
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_with(AtticUnit::Number, f)
+    }
+}
+
+impl FromStr for AtticNumeral {
+    type Err = ParseNumeralError;
+
+    /// Parses an ``AtticNumeral`` from its canonical string, in any unit.
+    ///
+    /// Returns ``AtticNumeral`` or ``ParseNumeralError``.
+    fn from_str(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse(s)
+    }
+}
+
+/// The value of Μ, the largest sign shared by all units
+const MYRIAD: u32 = 10_000;
+
+/// The values of the signs, from the largest to the smallest
+static VALUES: [u32; 10] = [50_000, 10_000, 5000, 1000, 500, 100, 50, 10, 5, 1];
+
+/// The signs of a plain number
+static NUMBER: [char; 10] = [
+    '\u{10147}',
+    'Μ',
+    '\u{10146}',
+    'Χ',
+    '\u{10145}',
+    'Η',
+    '\u{10144}',
+    'Δ',
+    'Π',
+    'Ι',
+];
+
+/// The signs of a sum in drachmas
+static DRACHMAS: [char; 10] = [
+    '\u{10147}',
+    'Μ',
+    '\u{10146}',
+    'Χ',
+    '\u{10145}',
+    'Η',
+    '\u{10144}',
+    'Δ',
+    'Π',
+    '\u{10142}',
+];
+
+/// The signs of a sum in talents
+static TALENTS: [char; 10] = [
+    '\u{10147}',
+    'Μ',
+    '\u{1014e}',
+    '\u{1014d}',
+    '\u{1014c}',
+    '\u{1014b}',
+    '\u{1014a}',
+    '\u{10149}',
+    '\u{10148}',
+    'Τ',
+];
+
+impl TryFrom<u8> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``u8``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u8) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u16> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``u16``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u32> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``u32``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<u64> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``u64``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<u128> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``u128``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<usize> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``usize``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i8> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``i8``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i8) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i16> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``i16``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i16) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i32> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``i32``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i32) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i64> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``i64``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i128> for AtticNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``AtticNumeral`` from an ``i128``.
+    ///
+    /// Returns ``AtticNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
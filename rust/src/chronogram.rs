@@ -0,0 +1,175 @@
+//! # kalendarium chronograms
+//!
+//! A chronogram is an inscription whose numeral letters, picked out as capitals, add up to a
+//! year, such as ``ChrIstVs DVX ergo trIVMphVs`` for 1632. Every numeral letter counts, wherever
+//! it stands and in whatever order, so the letters are summed rather than parsed as a numeral.
+//! ``ChronogramRules`` chooses which letters count: only the capitals or every letter, and
+//! whether U, W and J are read as V, VV and I.
+//!
+//! https://en.wikipedia.org/wiki/Chronogram
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{MAX, OutOfRangeError, Result, RomanNumeral};
+
+/// The rules for which letters of a chronogram count
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let chronogram: Chronogram = ChronogramRules::new().read("ChrIstVs DVX ergo trIVMphVs")?;
+///    assert_eq!(chronogram.value.as_u64(), 1632);
+///    assert_eq!(chronogram.letters.len(), 10);
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ChronogramRules {
+    capitals_only: bool,
+    u_as_v: bool,
+    w_as_double_v: bool,
+    j_as_i: bool,
+}
+
+/// The numeral letters of a text read by ``ChronogramRules::read``, and their sum
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Chronogram {
+    /// The sum of the numeral letters
+    pub value: RomanNumeral,
+    /// Every letter which counts towards the sum, in the order they appear in the text
+    pub letters: Vec<ChronogramLetter>,
+}
+
+/// A single numeral letter of a chronogram
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ChronogramLetter {
+    /// The letter as written in the text
+    pub letter: char,
+    /// The value the letter counts for
+    pub value: u64,
+    /// The byte offset of the letter in the text
+    pub position: usize,
+}
+
+impl ChronogramRules {
+    /// Creates the default rules: only capitals count, and U, W and J are read as V, VV and I.
+    #[must_use]
+    pub const fn new() -> Self {
+        ChronogramRules {
+            capitals_only: true,
+            u_as_v: true,
+            w_as_double_v: true,
+            j_as_i: true,
+        }
+    }
+
+    /// Sets whether only capital letters count, or lowercase numeral letters as well.
+    #[must_use]
+    pub const fn capitals_only(self, capitals_only: bool) -> Self {
+        ChronogramRules {
+            capitals_only,
+            ..self
+        }
+    }
+
+    /// Sets whether U counts as V, for five.
+    #[must_use]
+    pub const fn u_as_v(self, u_as_v: bool) -> Self {
+        ChronogramRules { u_as_v, ..self }
+    }
+
+    /// Sets whether W counts as VV, for ten.
+    #[must_use]
+    pub const fn w_as_double_v(self, w_as_double_v: bool) -> Self {
+        ChronogramRules {
+            w_as_double_v,
+            ..self
+        }
+    }
+
+    /// Sets whether J counts as I, for one.
+    #[must_use]
+    pub const fn j_as_i(self, j_as_i: bool) -> Self {
+        ChronogramRules { j_as_i, ..self }
+    }
+
+    /// Finds the numeral letters of ``text`` under these rules, and returns them with their sum.
+    /// Requires the sum to be at most ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let rules = ChronogramRules::new().capitals_only(false);
+    ///    let chronogram: Chronogram = rules.read("mix")?;
+    ///    assert_eq!(chronogram.value.as_u64(), 1011);
+    ///    assert_eq!(chronogram.letters[1].letter, 'i');
+    ///
+    pub fn read(&self, text: &str) -> Result<Chronogram, OutOfRangeError> {
+        let letters: Vec<ChronogramLetter> = text
+            .char_indices()
+            .filter_map(|(position, letter)| {
+                self.letter_value(letter).map(|value| ChronogramLetter {
+                    letter,
+                    value,
+                    position,
+                })
+            })
+            .collect();
+        let value = letters
+            .iter()
+            .try_fold(0_u64, |sum, letter| sum.checked_add(letter.value))
+            .filter(|&sum| sum <= MAX)
+            .ok_or(OutOfRangeError)?;
+        Ok(Chronogram {
+            value: RomanNumeral(value),
+            letters,
+        })
+    }
+
+    /// Whether the numeral letters of ``text`` add up to ``year`` under these rules.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let rules = ChronogramRules::new();
+    ///    assert!(rules.encodes("ChrIstVs DVX ergo trIVMphVs", 1632));
+    ///    assert!(!rules.encodes("ChrIstVs DVX ergo trIVMphVs", 1633));
+    ///
+    #[must_use]
+    pub fn encodes(&self, text: &str, year: u64) -> bool {
+        self.read(text)
+            .is_ok_and(|chronogram| chronogram.value.as_u64() == year)
+    }
+
+    /// Helper function for the value a letter counts for, if any
+    fn letter_value(&self, letter: char) -> Option<u64> {
+        if self.capitals_only && !letter.is_ascii_uppercase() {
+            return None;
+        }
+        match letter.to_ascii_uppercase() {
+            'I' => Some(1),
+            'J' if self.j_as_i => Some(1),
+            'V' => Some(5),
+            'U' if self.u_as_v => Some(5),
+            'X' => Some(10),
+            'W' if self.w_as_double_v => Some(10),
+            'L' => Some(50),
+            'C' => Some(100),
+            'D' => Some(500),
+            'M' => Some(1000),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ChronogramRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,449 @@
+//! # kalendarium counter styles
+//!
+//! The labels of lists, outlines and pages, after the CSS ``list-style-type`` keywords: decimal,
+//! decimal-leading-zero, upper-roman and lower-roman, upper-alpha and lower-alpha, and
+//! lower-greek. As in CSS, the Roman styles only cover 1 to 3999 and the alphabetic styles start
+//! at 1; any other value falls back to decimal, so page 4000 is labelled 4000 rather than with a
+//! vinculum. Lowercase Roman labels write five with v, e.g. xiv.
+//!
+//! An ``Outline`` chooses a counter style for each level of a nested outline, I. → A. → 1. →
+//! a. → i. by default, and page ranges are written and read as e.g. pp. xii–xv.
+//!
+//! https://www.w3.org/TR/css-counter-styles-3/
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::numeral::Canonical;
+use crate::{
+    Case, NumeralStyle, ParseErrorKind, ParseFormatError, ParseNumeralError, Result, RomanNumeral,
+    VLetter,
+};
+use core::fmt::{self, Write};
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+/// A counter style, named after its CSS ``list-style-type`` keyword
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub enum CounterStyle {
+    /// 1, 2, 3, …
+    #[default]
+    Decimal,
+    /// 01, 02, 03, …, 10
+    DecimalLeadingZero,
+    /// I, II, III, …, MMMCMXCIX
+    UpperRoman,
+    /// i, ii, iii, …, mmmcmxcix
+    LowerRoman,
+    /// A, B, …, Z, AA, AB, …
+    UpperAlpha,
+    /// a, b, …, z, aa, ab, …
+    LowerAlpha,
+    /// α, β, …, ω, αα, αβ, …
+    LowerGreek,
+}
+
+const UPPER_ROMAN: NumeralStyle = NumeralStyle::UPPERCASE;
+const LOWER_ROMAN: NumeralStyle = NumeralStyle::LOWERCASE.v_letter(VLetter::V);
+
+const UPPER_ALPHA: [char; 26] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+const LOWER_ALPHA: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+const LOWER_GREEK: [char; 24] = [
+    'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'σ', 'τ',
+    'υ', 'φ', 'χ', 'ψ', 'ω',
+];
+
+/// The en dash between the first and last page of a range
+const PAGE_DASH: char = '–';
+
+impl CounterStyle {
+    /// Every counter style, in the order they are declared
+    pub const ALL: [CounterStyle; 7] = [
+        CounterStyle::Decimal,
+        CounterStyle::DecimalLeadingZero,
+        CounterStyle::UpperRoman,
+        CounterStyle::LowerRoman,
+        CounterStyle::UpperAlpha,
+        CounterStyle::LowerAlpha,
+        CounterStyle::LowerGreek,
+    ];
+
+    /// The CSS keyword of the counter style, e.g. lower-roman.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            CounterStyle::Decimal => "decimal",
+            CounterStyle::DecimalLeadingZero => "decimal-leading-zero",
+            CounterStyle::UpperRoman => "upper-roman",
+            CounterStyle::LowerRoman => "lower-roman",
+            CounterStyle::UpperAlpha => "upper-alpha",
+            CounterStyle::LowerAlpha => "lower-alpha",
+            CounterStyle::LowerGreek => "lower-greek",
+        }
+    }
+
+    /// The values the counter style writes in its own symbols. Other values are written in
+    /// decimal.
+    #[must_use]
+    pub const fn range(self) -> RangeInclusive<u64> {
+        match self {
+            CounterStyle::Decimal | CounterStyle::DecimalLeadingZero => 0..=u64::MAX,
+            CounterStyle::UpperRoman | CounterStyle::LowerRoman => 1..=3999,
+            CounterStyle::UpperAlpha | CounterStyle::LowerAlpha | CounterStyle::LowerGreek => {
+                1..=u64::MAX
+            }
+        }
+    }
+
+    /// Converts ``value`` to a label in this counter style, without any suffix.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    assert_eq!("xiv", CounterStyle::LowerRoman.format(14));
+    ///    assert_eq!("4000", CounterStyle::LowerRoman.format(4000));
+    ///    assert_eq!("AB", CounterStyle::UpperAlpha.format(28));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format(self, value: u64) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write(value, &mut out);
+        out
+    }
+
+    /// Writes ``value`` as a label in this counter style to any ``fmt::Write``, without
+    /// allocating.
+    pub fn write<W: Write>(self, value: u64, out: &mut W) -> fmt::Result {
+        if !self.range().contains(&value) {
+            return write!(out, "{value}");
+        }
+        match self {
+            CounterStyle::Decimal => write!(out, "{value}"),
+            CounterStyle::DecimalLeadingZero => write!(out, "{value:02}"),
+            CounterStyle::UpperRoman => RomanNumeral(value).write_with(&UPPER_ROMAN, out),
+            CounterStyle::LowerRoman => RomanNumeral(value).write_with(&LOWER_ROMAN, out),
+            CounterStyle::UpperAlpha => Self::write_alphabetic(value, &UPPER_ALPHA, out),
+            CounterStyle::LowerAlpha => Self::write_alphabetic(value, &LOWER_ALPHA, out),
+            CounterStyle::LowerGreek => Self::write_alphabetic(value, &LOWER_GREEK, out),
+        }
+    }
+
+    /// Parses a label written in this counter style, without any suffix, back into its value.
+    ///
+    /// Only the label ``write`` writes is accepted, including the decimal fallback outside the
+    /// range of the style.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    assert_eq!(Ok(14), CounterStyle::LowerRoman.parse("xiv"));
+    ///    assert_eq!(Ok(4000), CounterStyle::LowerRoman.parse("4000"));
+    ///    assert_eq!(Ok(28), CounterStyle::UpperAlpha.parse("AB"));
+    ///
+    pub fn parse(self, label: &str) -> Result<u64, ParseNumeralError> {
+        if label.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        let value = if label.bytes().all(|b| b.is_ascii_digit()) {
+            label
+                .parse::<u64>()
+                .map_err(|_| ParseNumeralError::new(ParseErrorKind::OutOfRange, 0))?
+        } else {
+            match self {
+                CounterStyle::Decimal | CounterStyle::DecimalLeadingZero => {
+                    let position = label.bytes().position(|b| !b.is_ascii_digit());
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::InvalidCharacter,
+                        position.unwrap_or(0),
+                    ));
+                }
+                CounterStyle::UpperRoman => Self::roman_value(label, Case::Upper)?,
+                CounterStyle::LowerRoman => Self::roman_value(label, Case::Lower)?,
+                CounterStyle::UpperAlpha => Self::alphabetic_value(label, &UPPER_ALPHA)?,
+                CounterStyle::LowerAlpha => Self::alphabetic_value(label, &LOWER_ALPHA)?,
+                CounterStyle::LowerGreek => Self::alphabetic_value(label, &LOWER_GREEK)?,
+            }
+        };
+        let mut canonical = Canonical::new(label);
+        if self.write(value, &mut canonical).is_ok() && canonical.is_complete() {
+            Ok(value)
+        } else {
+            Err(ParseNumeralError::new(
+                ParseErrorKind::NonCanonical,
+                canonical.matched,
+            ))
+        }
+    }
+
+    /// Converts a range of pages to a string in this counter style, e.g. pp. xii–xv, or p. xii
+    /// for a single page.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    assert_eq!("pp. xii–xv", CounterStyle::LowerRoman.format_pages(12..=15));
+    ///    assert_eq!("p. 7", CounterStyle::Decimal.format_pages(7..=7));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format_pages(self, pages: RangeInclusive<u64>) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_pages(pages, &mut out);
+        out
+    }
+
+    /// Writes a range of pages in this counter style to any ``fmt::Write``, without allocating.
+    pub fn write_pages<W: Write>(self, pages: RangeInclusive<u64>, out: &mut W) -> fmt::Result {
+        let (first, last) = pages.into_inner();
+        if first == last {
+            out.write_str("p. ")?;
+            self.write(first, out)
+        } else {
+            out.write_str("pp. ")?;
+            self.write(first, out)?;
+            out.write_char(PAGE_DASH)?;
+            self.write(last, out)
+        }
+    }
+
+    /// Parses a page or a range of pages written in this counter style, e.g. pp. xii–xv or
+    /// p. xii. The p. or pp. may be left out, and the pages may be joined by a hyphen instead
+    /// of an en dash.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    assert_eq!(Ok(12..=15), CounterStyle::LowerRoman.parse_pages("pp. xii–xv"));
+    ///    assert_eq!(Ok(12..=12), CounterStyle::LowerRoman.parse_pages("xii"));
+    ///
+    pub fn parse_pages(self, s: &str) -> Result<RangeInclusive<u64>, ParseNumeralError> {
+        let pages = s
+            .strip_prefix("pp. ")
+            .or_else(|| s.strip_prefix("p. "))
+            .unwrap_or(s);
+        let start = s.len() - pages.len();
+        let offset = |err: ParseNumeralError, by: usize| {
+            ParseNumeralError::new(err.kind(), err.position() + by)
+        };
+        match pages.find([PAGE_DASH, '-']) {
+            None => {
+                let page = self.parse(pages).map_err(|err| offset(err, start))?;
+                Ok(page..=page)
+            }
+            Some(dash) => {
+                let second = dash + pages[dash..].chars().next().map_or(1, char::len_utf8);
+                let first = self
+                    .parse(&pages[..dash])
+                    .map_err(|err| offset(err, start))?;
+                let last = self
+                    .parse(&pages[second..])
+                    .map_err(|err| offset(err, start + second))?;
+                if first > last {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::NonCanonical,
+                        start + second,
+                    ));
+                }
+                Ok(first..=last)
+            }
+        }
+    }
+
+    /// Helper function to write a value in a bijective base of the letters of ``alphabet``, e.g.
+    /// Z, AA, AB
+    fn write_alphabetic<W: Write>(value: u64, alphabet: &[char], out: &mut W) -> fmt::Result {
+        // u64::MAX takes 14 letters in base 26, and 14 in base 24
+        let mut letters = ['\0'; 16];
+        let mut count = 0;
+        let base = alphabet.len() as u64;
+        let mut rest = value;
+        while rest > 0 {
+            rest -= 1;
+            letters[count] = alphabet[(rest % base) as usize];
+            count += 1;
+            rest /= base;
+        }
+        letters[..count]
+            .iter()
+            .rev()
+            .try_for_each(|&letter| out.write_char(letter))
+    }
+
+    /// Helper function for the value of a label in a bijective base of the letters of
+    /// ``alphabet``
+    fn alphabetic_value(label: &str, alphabet: &[char]) -> Result<u64, ParseNumeralError> {
+        let base = alphabet.len() as u64;
+        label
+            .char_indices()
+            .try_fold(0_u64, |value, (position, c)| {
+                let digit = alphabet.iter().position(|&letter| letter == c).ok_or(
+                    ParseNumeralError::new(ParseErrorKind::InvalidCharacter, position),
+                )?;
+                value
+                    .checked_mul(base)
+                    .and_then(|value| value.checked_add(digit as u64 + 1))
+                    .ok_or(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0))
+            })
+    }
+
+    /// Helper function for the value of a Roman numeral label in ``case``, with subtraction
+    fn roman_value(label: &str, case: Case) -> Result<u64, ParseNumeralError> {
+        let mut value: u64 = 0;
+        let mut previous: u64 = 0;
+        for (position, c) in label.char_indices() {
+            let letters = match case {
+                Case::Upper => "IVXLCDM",
+                Case::Lower => "ivxlcdm",
+            };
+            let symbol = letters
+                .contains(c)
+                .then(|| RomanNumeral::symbol_value(c))
+                .flatten()
+                .ok_or(ParseNumeralError::new(
+                    ParseErrorKind::InvalidCharacter,
+                    position,
+                ))?;
+            let symbol = u64::from(symbol);
+            value = value.saturating_add(symbol);
+            if previous < symbol {
+                value -= 2 * previous;
+            }
+            previous = symbol;
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for CounterStyle {
+    /// Writes the CSS keyword of the counter style.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for CounterStyle {
+    type Err = ParseFormatError;
+
+    /// Parses a counter style from its CSS keyword, also accepting upper-latin and lower-latin.
+    fn from_str(s: &str) -> Result<Self, ParseFormatError> {
+        match s {
+            "upper-latin" => Ok(CounterStyle::UpperAlpha),
+            "lower-latin" => Ok(CounterStyle::LowerAlpha),
+            _ => Self::ALL
+                .into_iter()
+                .find(|style| style.name() == s)
+                .ok_or(ParseFormatError),
+        }
+    }
+}
+
+/// The counter styles of the levels of a nested outline, and the suffix after each label
+///
+/// Deeper levels than there are styles start again from the first style.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let labels: Vec<String> = (0..5).map(|depth| Outline::DEFAULT.label(depth, 1)).collect();
+///    assert_eq!(labels, ["I.", "A.", "1.", "a.", "i."]);
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Outline<'a> {
+    styles: &'a [CounterStyle],
+    suffix: &'a str,
+}
+
+impl<'a> Outline<'a> {
+    /// The usual outline: I. → A. → 1. → a. → i.
+    pub const DEFAULT: Outline<'static> = Outline {
+        styles: &[
+            CounterStyle::UpperRoman,
+            CounterStyle::UpperAlpha,
+            CounterStyle::Decimal,
+            CounterStyle::LowerAlpha,
+            CounterStyle::LowerRoman,
+        ],
+        suffix: ".",
+    };
+
+    /// Creates an outline with one counter style per level, and ``suffix`` after each label.
+    #[must_use]
+    pub const fn new(styles: &'a [CounterStyle], suffix: &'a str) -> Self {
+        Outline { styles, suffix }
+    }
+
+    /// The counter style of the level at ``depth``, starting from 0. An outline without styles
+    /// is decimal.
+    #[must_use]
+    pub const fn style(&self, depth: usize) -> CounterStyle {
+        if self.styles.is_empty() {
+            CounterStyle::Decimal
+        } else {
+            self.styles[depth % self.styles.len()]
+        }
+    }
+
+    /// Converts ``value`` to the label of an item at ``depth``, with its suffix.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    assert_eq!("iv.", Outline::DEFAULT.label(4, 4));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn label(&self, depth: usize, value: u64) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_label(depth, value, &mut out);
+        out
+    }
+
+    /// Writes the label of an item at ``depth`` to any ``fmt::Write``, without allocating.
+    pub fn write_label<W: Write>(&self, depth: usize, value: u64, out: &mut W) -> fmt::Result {
+        self.style(depth).write(value, out)?;
+        out.write_str(self.suffix)
+    }
+
+    /// Parses the label of an item at ``depth``, with its suffix, back into its value.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    assert_eq!(Ok(2), Outline::DEFAULT.parse_label(1, "B."));
+    ///
+    pub fn parse_label(&self, depth: usize, label: &str) -> Result<u64, ParseNumeralError> {
+        let value = label
+            .strip_suffix(self.suffix)
+            .ok_or(ParseNumeralError::new(
+                ParseErrorKind::NonCanonical,
+                label.len(),
+            ))?;
+        self.style(depth).parse(value)
+    }
+}
@@ -0,0 +1,429 @@
+//! # kalendarium Cyrillic numerals
+//!
+//! The Church Slavonic numerals borrow the Greek system, giving letters of the Cyrillic alphabet
+//! the values of their Greek counterparts: а to ѳ for the units, і to ч for the tens and р to ц
+//! for the hundreds. The teens are written with the units first, as they are spoken, e.g. аі for
+//! 11, and otherwise the larger letters come first. A titlo (҃, U+0483) over the second letter
+//! from the end, or over the only letter, marks them as a number, e.g. кв҃ for 22 and ѕ҃ for 6.
+//!
+//! The thousands sign (҂, U+0482) before a units letter multiplies it by 1000, e.g. ҂аѱ҃ѕ for
+//! 1706. The larger places are written with a units letter and a combining sign: a circle
+//! (U+20DD) for tens of thousands, the hundred thousands sign (U+0488), the millions sign
+//! (U+0489), and the ten and hundred millions signs (U+A670, U+A671). Values from 1 to
+//! 999,999,999 are supported.
+//!
+//! https://en.wikipedia.org/wiki/Cyrillic_numerals
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::numeral::Canonical;
+use crate::{OutOfRangeError, ParseErrorKind, ParseNumeralError, Result};
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// A Cyrillic alphabetic numeral
+///
+/// Values from 1 to 999,999,999 are supported
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CyrillicNumeral(pub u32);
+
+/// A complete description of how to write out a ``CyrillicNumeral``
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CyrillicStyle {
+    titlo: bool,
+}
+
+impl CyrillicStyle {
+    /// Creates the default style, with the titlo.
+    #[must_use]
+    pub const fn new() -> Self {
+        CyrillicStyle { titlo: true }
+    }
+
+    /// Sets whether the titlo marks the letters as a number.
+    #[must_use]
+    pub const fn titlo(self, titlo: bool) -> Self {
+        CyrillicStyle { titlo }
+    }
+}
+
+impl Default for CyrillicStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Helper type for a letter of a numeral, with the sign before or after it
+#[derive(Clone, Copy)]
+struct Glyph {
+    letter: char,
+    thousands: bool,
+    mark: Option<char>,
+}
+
+impl CyrillicNumeral {
+    /// The value of the smallest Cyrillic numeral
+    pub const MIN: u32 = 1;
+    /// The value of the largest Cyrillic numeral
+    pub const MAX: u32 = 999_999_999;
+
+    /// Creates a ``CyrillicNumeral`` for any value in range.
+    /// Requires ``value`` to be between 1 and 999,999,999, as there is no Cyrillic zero.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: CyrillicNumeral = CyrillicNumeral::new(1706)?;
+    ///    assert_eq!("҂аѱ҃ѕ", year.to_string());
+    ///
+    pub const fn new(value: u32) -> Result<Self, OutOfRangeError> {
+        if value >= Self::MIN && value <= Self::MAX {
+            Ok(CyrillicNumeral(value))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Return the value of this ``CyrillicNumeral`` as a ``u32``.
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Converts a ``CyrillicNumeral`` to a string written in ``style``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: CyrillicNumeral = CyrillicNumeral::new(15)?;
+    ///    assert_eq!("єі", num.format_with(&CyrillicStyle::new().titlo(false)));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format_with(self, style: &CyrillicStyle) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_with(style, &mut out);
+        out
+    }
+
+    /// Writes a ``CyrillicNumeral`` in ``style`` to any ``fmt::Write``, without allocating.
+    pub fn write_with<W: Write>(&self, style: &CyrillicStyle, out: &mut W) -> fmt::Result {
+        let glyphs = self.glyphs();
+        let count = glyphs.iter().flatten().count();
+        // The titlo goes over the second letter from the end, or over the only letter
+        let titlo = count.saturating_sub(2);
+        for (index, glyph) in glyphs.iter().flatten().enumerate() {
+            if glyph.thousands {
+                out.write_char(THOUSANDS)?;
+            }
+            out.write_char(glyph.letter)?;
+            if style.titlo && index == titlo {
+                out.write_char(TITLO)?;
+            }
+            if let Some(mark) = glyph.mark {
+                out.write_char(mark)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Helper function returning the letters of the numeral, from the largest
+    fn glyphs(&self) -> [Option<Glyph>; 9] {
+        let mut glyphs = [None; 9];
+        let mut count = 0;
+        let mut push = |letter: char, thousands: bool, mark: Option<char>| {
+            glyphs[count] = Some(Glyph {
+                letter,
+                thousands,
+                mark,
+            });
+            count += 1;
+        };
+        // The places from the hundred millions down to the tens of thousands
+        for (place, mark) in MARKS.iter().enumerate().rev() {
+            let digit = self.0 / 10_u32.pow(place as u32 + 4) % 10;
+            if digit > 0 {
+                push(UNITS[digit as usize - 1], false, Some(*mark));
+            }
+        }
+        let thousands = self.0 / 1000 % 10;
+        if thousands > 0 {
+            push(UNITS[thousands as usize - 1], true, None);
+        }
+        let (hundreds, tens, units) = (self.0 / 100 % 10, self.0 / 10 % 10, self.0 % 10);
+        if hundreds > 0 {
+            push(HUNDREDS[hundreds as usize - 1], false, None);
+        }
+        if tens == 1 && units > 0 {
+            push(UNITS[units as usize - 1], false, None);
+            push(TENS[0], false, None);
+        } else {
+            if tens > 0 {
+                push(TENS[tens as usize - 1], false, None);
+            }
+            if units > 0 {
+                push(UNITS[units as usize - 1], false, None);
+            }
+        }
+        glyphs
+    }
+
+    /// Parses a ``CyrillicNumeral`` from a string.
+    ///
+    /// Only the canonical forms are accepted, with or without the titlo. The variant letters е
+    /// (5), ѻ (70), ҁ (90), у, ѹ and ꙋ (400), and ѿ (800) are read as well.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: CyrillicNumeral = CyrillicNumeral::parse("҂аѱ҃ѕ")?;
+    ///    assert_eq!(year.as_u32(), 1706);
+    ///
+    pub fn parse(s: &str) -> Result<Self, ParseNumeralError> {
+        if s.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        let mut value: u64 = 0;
+        let mut last: u64 = 0;
+        let mut thousands = false;
+        for (position, character) in s.char_indices() {
+            if character == THOUSANDS {
+                thousands = true;
+            } else if let Some(place) = MARKS.iter().position(|&mark| mark == character) {
+                // The sign multiplies the letter before it, which was already counted once
+                let power = 10_u64.pow(place as u32 + 4);
+                value = value.saturating_add(last.saturating_mul(power - 1));
+            } else if character != TITLO {
+                last = match Self::letter_value(Self::normalise(character)) {
+                    Some(letter) if thousands => u64::from(letter) * 1000,
+                    Some(letter) => u64::from(letter),
+                    None => {
+                        return Err(ParseNumeralError::new(
+                            ParseErrorKind::InvalidCharacter,
+                            position,
+                        ));
+                    }
+                };
+                value = value.saturating_add(last);
+                thousands = false;
+            }
+        }
+        let value = match u32::try_from(value) {
+            Ok(value) if value <= Self::MAX => value,
+            _ => return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0)),
+        };
+        if value == 0 {
+            return Err(ParseNumeralError::new(ParseErrorKind::NonCanonical, 0));
+        }
+
+        let style = CyrillicStyle::new().titlo(s.contains(TITLO));
+        let mut canonical = Canonical::normalised(s, CyrillicNumeral::normalise);
+        let result = CyrillicNumeral(value).write_with(&style, &mut canonical);
+        if result.is_ok() && canonical.is_complete() {
+            Ok(CyrillicNumeral(value))
+        } else {
+            Err(ParseNumeralError::new(
+                ParseErrorKind::NonCanonical,
+                canonical.matched,
+            ))
+        }
+    }
+
+    /// Helper function returning the value of a letter
+    fn letter_value(character: char) -> Option<u32> {
+        [(&UNITS, 1), (&TENS, 10), (&HUNDREDS, 100)]
+            .into_iter()
+            .find_map(|(letters, power)| {
+                let index = letters.iter().position(|&letter| letter == character)?;
+                Some((index as u32 + 1) * power)
+            })
+    }
+
+    /// Helper function to read the variant letters as the canonical ones
+    const fn normalise(character: char) -> char {
+        match character {
+            'е' => 'є',
+            'ѻ' => 'о',
+            'ҁ' => 'ч',
+            'у' | 'ѹ' | 'ꙋ' => 'ѵ',
+            'ѿ' => 'ѡ',
+            _ => character,
+        }
+    }
+}
+
+impl fmt::Display for CyrillicNumeral {
+    /// Converts a ``CyrillicNumeral`` to a string with the titlo.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: CyrillicNumeral = CyrillicNumeral::new(42)?;
+    ///    assert_eq!("м҃в", answer.to_string());
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_with(&CyrillicStyle::new(), f)
+    }
+}
+
+impl FromStr for CyrillicNumeral {
+    type Err = ParseNumeralError;
+
+    /// Parses a ``CyrillicNumeral`` from its canonical string.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``ParseNumeralError``.
+    fn from_str(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse(s)
+    }
+}
+
+/// The combining titlo, which marks the letters as a number
+const TITLO: char = '\u{483}';
+
+/// The thousands sign, which multiplies the following letter by 1000
+const THOUSANDS: char = '\u{482}';
+
+/// The combining signs which multiply a letter by ten thousand up to a hundred million
+static MARKS: [char; 5] = ['\u{20dd}', '\u{488}', '\u{489}', '\u{a670}', '\u{a671}'];
+
+/// The letters for one to nine
+static UNITS: [char; 9] = ['а', 'в', 'г', 'д', 'є', 'ѕ', 'з', 'и', 'ѳ'];
+
+/// The letters for ten to ninety
+static TENS: [char; 9] = ['і', 'к', 'л', 'м', 'н', 'ѯ', 'о', 'п', 'ч'];
+
+/// The letters for one hundred to nine hundred
+static HUNDREDS: [char; 9] = ['р', 'с', 'т', 'ѵ', 'ф', 'х', 'ѱ', 'ѡ', 'ц'];
+
+impl TryFrom<u8> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``u8``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u8) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u16> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``u16``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u32> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``u32``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<u64> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``u64``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<u128> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``u128``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<usize> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``usize``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i8> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``i8``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i8) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i16> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``i16``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i16) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i32> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``i32``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i32) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i64> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``i64``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i128> for CyrillicNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``CyrillicNumeral`` from an ``i128``.
+    ///
+    /// Returns ``CyrillicNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
@@ -53,9 +53,9 @@ impl Kalendarium {
             } else {
                 date_str.push_str(year);
             }
-            date_str.push_str("-");
+            date_str.push('-');
             date_str.push_str(month);
-            date_str.push_str("-");
+            date_str.push('-');
             date_str.push_str(day);
             let cal = Calendar::REFORM1582;
             let date = cal.parse_date(&date_str).unwrap();
@@ -81,9 +81,9 @@ impl Kalendarium {
         let mut out = String::new();
 
         out.push_str(&Self::roman_day(self));
-        out.push_str(" ");
+        out.push(' ');
         out.push_str(&Self::roman_year(self));
-        out.push_str(" ");
+        out.push(' ');
         out.push_str(&Self::roman_festival_day(self));
         out.push_str(&Self::roman_day_of_week(self));
         out
@@ -97,11 +97,7 @@ impl Kalendarium {
             true
         } else if year % 4 == 0 {
             if (year % 100) == 0 {
-                if year % 400 == 0 {
-                    true
-                } else {
-                    false
-                }
+                year % 400 == 0
             } else {
                 true
             }
@@ -116,7 +112,7 @@ impl Kalendarium {
         let year: i16 = self.0.year() as i16;
         // No longer have to offset for year zero because I do that in the constructor now
         out.push_str(
-            &RomanNumeral::new((753_i16 + year) as u32)
+            &RomanNumeral::new((753_i16 + year) as u64)
                 .unwrap()
                 .to_uppercase(),
         );
@@ -129,10 +125,10 @@ impl Kalendarium {
         let month: u8 = self.0.month() as u8;
         let day: u8 = self.0.day() as u8;
         let mut out = String::new();
-        for (_index, festival_day) in FERIAE.iter().enumerate() {
+        for festival_day in FERIAE.iter() {
             if festival_day.month == month && festival_day.day == day {
                 out.push_str(festival_day.festival);
-                out.push_str(" ");
+                out.push(' ');
                 break;
             }
         }
@@ -172,7 +168,7 @@ impl Kalendarium {
             } else {
                 out.push_str("ante diem ");
                 out.push_str(
-                    &RomanNumeral::new((1 + MENSES[month - 1].nones - day) as u32)
+                    &RomanNumeral::new((1 + MENSES[month - 1].nones - day) as u64)
                         .unwrap()
                         .to_uppercase(),
                 );
@@ -191,7 +187,7 @@ impl Kalendarium {
             } else {
                 out.push_str("ante diem ");
                 out.push_str(
-                    &RomanNumeral::new((1 + MENSES[month - 1].ides - day) as u32)
+                    &RomanNumeral::new((1 + MENSES[month - 1].ides - day) as u64)
                         .unwrap()
                         .to_uppercase(),
                 );
@@ -211,7 +207,7 @@ impl Kalendarium {
             } else {
                 out.push_str("ante diem ");
                 out.push_str(
-                    &RomanNumeral::new((2 + (MENSES[month - 1].finis + leap_day) - day) as u32)
+                    &RomanNumeral::new((2 + (MENSES[month - 1].finis + leap_day) - day) as u64)
                         .unwrap()
                         .to_uppercase(),
                 );
@@ -7,6 +7,181 @@ pub struct OutOfRangeError;
 
 impl fmt::Display for OutOfRangeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Number out of range (must be between 1 and 9,999,999).")
+        write!(
+            f,
+            "Number out of range (must be between the MIN and MAX of the numeral)."
+        )
+    }
+}
+
+/// Returned as an error if a string names an unknown numeral system or style
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ParseFormatError;
+
+impl fmt::Display for ParseFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown numeral system or style.")
+    }
+}
+
+/// Returned as an error if a string cannot be parsed into a numeral
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ParseNumeralError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+/// The reason a string could not be parsed into a numeral
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The string was empty
+    Empty,
+    /// The string contains a character which is not part of any numeral
+    InvalidCharacter,
+    /// The string is made of numeral symbols, but not in their canonical form
+    NonCanonical,
+    /// The string is a valid numeral whose value is larger than ``MAX``
+    OutOfRange,
+}
+
+impl ParseNumeralError {
+    /// Creates a ``ParseNumeralError`` of ``kind`` found at byte offset ``position``.
+    pub(crate) const fn new(kind: ParseErrorKind, position: usize) -> Self {
+        ParseNumeralError { kind, position }
+    }
+
+    /// The reason parsing failed.
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the input at which parsing failed.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseNumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => write!(f, "Cannot parse a numeral from an empty string."),
+            ParseErrorKind::InvalidCharacter => {
+                write!(
+                    f,
+                    "Invalid numeral character at position {}.",
+                    self.position
+                )
+            }
+            ParseErrorKind::NonCanonical => {
+                write!(
+                    f,
+                    "Numeral is not in canonical form at position {}.",
+                    self.position
+                )
+            }
+            ParseErrorKind::OutOfRange => {
+                write!(
+                    f,
+                    "Number out of range (must be between the MIN and MAX of the numeral)."
+                )
+            }
+        }
+    }
+}
+
+/// Returned as an error if an expression of Roman numerals cannot be evaluated
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ExpressionError {
+    kind: ExpressionErrorKind,
+    position: usize,
+}
+
+/// The reason an expression could not be evaluated
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ExpressionErrorKind {
+    /// The expression, or a pair of parentheses, was empty
+    Empty,
+    /// A numeral in the expression could not be parsed
+    Numeral(ParseErrorKind),
+    /// An operator or parenthesis where a numeral was expected, or the other way around
+    UnexpectedToken,
+    /// The expression ended before it was complete, e.g. after an operator
+    UnexpectedEnd,
+    /// An opening parenthesis without a closing one, or the other way around
+    UnbalancedParenthesis,
+    /// Parentheses nested more than 256 deep
+    TooDeep,
+    /// A division or remainder by zero (N)
+    DivisionByZero,
+    /// A result larger than ``MAX`` or below zero
+    OutOfRange,
+}
+
+impl ExpressionError {
+    /// Creates an ``ExpressionError`` of ``kind`` found at byte offset ``position``.
+    pub(crate) const fn new(kind: ExpressionErrorKind, position: usize) -> Self {
+        ExpressionError { kind, position }
+    }
+
+    /// The reason evaluation failed.
+    #[must_use]
+    pub const fn kind(&self) -> ExpressionErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the expression at which evaluation failed.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ExpressionErrorKind::Empty => {
+                write!(f, "Empty expression at position {}.", self.position)
+            }
+            ExpressionErrorKind::Numeral(kind) => {
+                ParseNumeralError::new(kind, self.position).fmt(f)
+            }
+            ExpressionErrorKind::UnexpectedToken => {
+                write!(f, "Unexpected symbol at position {}.", self.position)
+            }
+            ExpressionErrorKind::UnexpectedEnd => {
+                write!(
+                    f,
+                    "Unexpected end of expression at position {}.",
+                    self.position
+                )
+            }
+            ExpressionErrorKind::UnbalancedParenthesis => {
+                write!(f, "Unbalanced parenthesis at position {}.", self.position)
+            }
+            ExpressionErrorKind::TooDeep => {
+                write!(
+                    f,
+                    "Parentheses nested too deep (at most 256) at position {}.",
+                    self.position
+                )
+            }
+            ExpressionErrorKind::DivisionByZero => {
+                write!(f, "Division by nulla at position {}.", self.position)
+            }
+            ExpressionErrorKind::OutOfRange => {
+                write!(
+                    f,
+                    "Result out of range (must be between 0 and 4,999,999,999) at position {}.",
+                    self.position
+                )
+            }
+        }
     }
 }
@@ -0,0 +1,346 @@
+//! # kalendarium Etruscan numerals
+//!
+//! The Roman numerals grew out of the Etruscan ones, written with the signs of the Old Italic
+//! block: 𐌠 for one, 𐌡 for five, 𐌢 for ten, 𐌣 for fifty and 𐌟 for a hundred. They were
+//! written additively, with up to four of each sign, e.g. 𐌢𐌢𐌡𐌠𐌠 for 27. Values from 1 to 499
+//! are supported.
+//!
+//! Etruscan inscriptions usually run from right to left. As the Old Italic characters are always
+//! displayed left to right, a right to left numeral is written here with its signs in reverse
+//! order, the smallest first, so that it reads as on the stone.
+//!
+//! https://en.wikipedia.org/wiki/Etruscan_numerals
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{OutOfRangeError, ParseErrorKind, ParseNumeralError, Result, RomanNumeral};
+use core::fmt::{self, Write};
+use core::iter;
+use core::str::FromStr;
+
+/// An Etruscan numeral
+///
+/// Values from 1 to 499 are supported
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct EtruscanNumeral(pub u32);
+
+/// The direction in which an ``EtruscanNumeral`` is written
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Direction {
+    /// From right to left, as in most inscriptions, with the smallest sign first, e.g. 𐌠𐌠𐌡𐌢𐌢
+    /// for 27
+    #[default]
+    RightToLeft,
+    /// From left to right, with the largest sign first, e.g. 𐌢𐌢𐌡𐌠𐌠 for 27
+    LeftToRight,
+}
+
+impl EtruscanNumeral {
+    /// The value of the smallest Etruscan numeral
+    pub const MIN: u32 = 1;
+    /// The value of the largest Etruscan numeral
+    pub const MAX: u32 = 499;
+
+    /// Creates an ``EtruscanNumeral`` for any value in range.
+    /// Requires ``value`` to be between 1 and 499.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: EtruscanNumeral = EtruscanNumeral::new(27)?;
+    ///    assert_eq!("𐌠𐌠𐌡𐌢𐌢", num.to_string());
+    ///
+    pub const fn new(value: u32) -> Result<Self, OutOfRangeError> {
+        if value >= Self::MIN && value <= Self::MAX {
+            Ok(EtruscanNumeral(value))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Return the value of this ``EtruscanNumeral`` as a ``u32``.
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Converts an ``EtruscanNumeral`` to a string written in ``direction``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: EtruscanNumeral = EtruscanNumeral::new(27)?;
+    ///    assert_eq!("𐌢𐌢𐌡𐌠𐌠", num.format_with(Direction::LeftToRight));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format_with(self, direction: Direction) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_with(direction, &mut out);
+        out
+    }
+
+    /// Writes an ``EtruscanNumeral`` in ``direction`` to any ``fmt::Write``, without allocating.
+    pub fn write_with<W: Write>(&self, direction: Direction, out: &mut W) -> fmt::Result {
+        match direction {
+            Direction::LeftToRight => self
+                .glyphs()
+                .try_for_each(|(etruscan, _)| out.write_char(etruscan)),
+            Direction::RightToLeft => self
+                .glyphs()
+                .rev()
+                .try_for_each(|(etruscan, _)| out.write_char(etruscan)),
+        }
+    }
+
+    /// Returns each Etruscan sign along with the Roman letter it became, from the largest, as
+    /// written from left to right. Reverse it for a numeral written from right to left.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: EtruscanNumeral = EtruscanNumeral::new(27)?;
+    ///    let roman: String = num.glyphs().map(|(_, roman)| roman).collect();
+    ///    assert_eq!("XXVII", roman);
+    ///
+    pub fn glyphs(&self) -> impl DoubleEndedIterator<Item = (char, char)> {
+        let value = self.0;
+        SIGNS
+            .iter()
+            .flat_map(move |&(sign, limit, etruscan, roman)| {
+                iter::repeat_n((etruscan, roman), (value % limit / sign) as usize)
+            })
+    }
+
+    /// Parses an ``EtruscanNumeral`` from a string written in either direction.
+    ///
+    /// Only the canonical additive forms are accepted.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: EtruscanNumeral = EtruscanNumeral::parse("𐌠𐌠𐌡𐌢𐌢")?;
+    ///    assert_eq!(num.as_u32(), 27);
+    ///
+    pub fn parse(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse_with_direction(s).map(|(num, _)| num)
+    }
+
+    /// Parses an ``EtruscanNumeral`` from a string, along with the direction it was written in.
+    ///
+    /// A numeral of a single kind of sign, such as 𐌢𐌢, is read as ``Direction::RightToLeft``.
+    pub fn parse_with_direction(s: &str) -> Result<(Self, Direction), ParseNumeralError> {
+        let mut value: u32 = 0;
+        for (position, character) in s.char_indices() {
+            match SIGNS
+                .iter()
+                .find(|&&(_, _, etruscan, _)| etruscan == character)
+            {
+                Some(&(sign, ..)) => value = value.saturating_add(sign),
+                None => {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::InvalidCharacter,
+                        position,
+                    ));
+                }
+            }
+        }
+        if value == 0 {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        if value > Self::MAX {
+            return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0));
+        }
+
+        let num = EtruscanNumeral(value);
+        let right_to_left = Self::matched(s, num.glyphs().rev());
+        if right_to_left == s.len() {
+            return Ok((num, Direction::RightToLeft));
+        }
+        let left_to_right = Self::matched(s, num.glyphs());
+        if left_to_right == s.len() {
+            return Ok((num, Direction::LeftToRight));
+        }
+        Err(ParseNumeralError::new(
+            ParseErrorKind::NonCanonical,
+            right_to_left.max(left_to_right),
+        ))
+    }
+
+    /// Helper function returning how many bytes of ``s`` match the canonical ``glyphs``
+    fn matched(s: &str, glyphs: impl Iterator<Item = (char, char)>) -> usize {
+        s.chars()
+            .zip(glyphs)
+            .take_while(|&(character, (etruscan, _))| character == etruscan)
+            .map(|(character, _)| character.len_utf8())
+            .sum()
+    }
+}
+
+impl fmt::Display for EtruscanNumeral {
+    /// Converts an ``EtruscanNumeral`` to a string written from right to left.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_with(Direction::RightToLeft, f)
+    }
+}
+
+impl FromStr for EtruscanNumeral {
+    type Err = ParseNumeralError;
+
+    /// Parses an ``EtruscanNumeral`` from its canonical string, in either direction.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``ParseNumeralError``.
+    fn from_str(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse(s)
+    }
+}
+
+impl From<EtruscanNumeral> for RomanNumeral {
+    /// Creates the ``RomanNumeral`` of the same value.
+    fn from(num: EtruscanNumeral) -> Self {
+        RomanNumeral(u64::from(num.0))
+    }
+}
+
+/// The value of each sign, the value below which it is used, the Etruscan sign and the Roman
+/// letter, from the largest
+static SIGNS: [(u32, u32, char, char); 5] = [
+    (100, 500, '\u{1031f}', 'C'),
+    (50, 100, '\u{10323}', 'L'),
+    (10, 50, '\u{10322}', 'X'),
+    (5, 10, '\u{10321}', 'V'),
+    (1, 5, '\u{10320}', 'I'),
+];
+
+impl TryFrom<u8> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``u8``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u8) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u16> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``u16``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u32> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``u32``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<u64> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``u64``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<u128> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``u128``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<usize> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``usize``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i8> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``i8``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i8) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i16> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``i16``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i16) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i32> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``i32``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i32) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i64> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``i64``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i128> for EtruscanNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates an ``EtruscanNumeral`` from an ``i128``.
+    ///
+    /// Returns ``EtruscanNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
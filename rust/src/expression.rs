@@ -0,0 +1,186 @@
+//! # kalendarium Roman numeral expressions
+//!
+//! A small calculator for infix expressions written in Roman numerals, such as ``XII + IV * II``.
+//! It understands ``+``, ``-``, ``*`` (or ``×``), ``/`` (or ``÷``) and ``%`` with the usual
+//! precedence, and parentheses. Every numeral is read with ``RomanNumeral::parse``, every step is
+//! range-checked by the ``RomanNumeral`` operators, and errors carry the byte offset in the
+//! expression where they were found.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{ExpressionError, ExpressionErrorKind, Result, RomanNumeral};
+use core::iter::Peekable;
+use core::str::CharIndices;
+
+/// The deepest nesting of parentheses evaluated, which bounds the recursion of the parser
+const MAX_DEPTH: usize = 256;
+
+impl RomanNumeral {
+    /// Evaluates an infix expression of Roman numerals.
+    ///
+    /// Division rounds down. Parentheses may be nested at most 256 deep. Returns an
+    /// ``ExpressionError`` with the position of the offending numeral, operator or parenthesis.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: RomanNumeral = RomanNumeral::evaluate("XII + IV * II")?;
+    ///    assert_eq!("XX", answer.to_string());
+    ///
+    ///    let answer: RomanNumeral = RomanNumeral::evaluate("(XII + IV) / (II × II)")?;
+    ///    assert_eq!("IV", answer.to_string());
+    ///
+    pub fn evaluate(expression: &str) -> Result<Self, ExpressionError> {
+        let mut parser = Parser {
+            source: expression,
+            chars: expression.char_indices().peekable(),
+            depth: 0,
+        };
+        if parser.peek().is_none() {
+            return Err(ExpressionError::new(ExpressionErrorKind::Empty, 0));
+        }
+        let value = parser.expression()?;
+        match parser.peek() {
+            None => Ok(value),
+            Some((position, ')')) => Err(ExpressionError::new(
+                ExpressionErrorKind::UnbalancedParenthesis,
+                position,
+            )),
+            Some((position, _)) => Err(ExpressionError::new(
+                ExpressionErrorKind::UnexpectedToken,
+                position,
+            )),
+        }
+    }
+}
+
+/// Helper recursive descent parser which evaluates as it reads
+struct Parser<'a> {
+    source: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    /// Helper function for a sum or difference of terms
+    fn expression(&mut self) -> Result<RomanNumeral, ExpressionError> {
+        let mut value = self.term()?;
+        while let Some((position, operator)) = self.peek() {
+            if !matches!(operator, '+' | '-') {
+                break;
+            }
+            self.chars.next();
+            let rhs = self.term()?;
+            let result = if operator == '+' {
+                value + rhs
+            } else {
+                value - rhs
+            };
+            value = Self::in_range(result, position)?;
+        }
+        Ok(value)
+    }
+
+    /// Helper function for a product, quotient or remainder of factors
+    fn term(&mut self) -> Result<RomanNumeral, ExpressionError> {
+        let mut value = self.factor()?;
+        while let Some((position, operator)) = self.peek() {
+            if !matches!(operator, '*' | '×' | '/' | '÷' | '%') {
+                break;
+            }
+            self.chars.next();
+            let rhs = self.factor()?;
+            let result = match operator {
+                '*' | '×' => value * rhs,
+                _ if rhs.as_u64() == 0 => {
+                    return Err(ExpressionError::new(
+                        ExpressionErrorKind::DivisionByZero,
+                        position,
+                    ));
+                }
+                '%' => value % rhs,
+                _ => value / rhs,
+            };
+            value = Self::in_range(result, position)?;
+        }
+        Ok(value)
+    }
+
+    /// Helper function for a numeral or a parenthesised expression
+    fn factor(&mut self) -> Result<RomanNumeral, ExpressionError> {
+        match self.peek() {
+            None => Err(ExpressionError::new(
+                ExpressionErrorKind::UnexpectedEnd,
+                self.source.len(),
+            )),
+            Some((position, '(')) => {
+                if self.depth == MAX_DEPTH {
+                    return Err(ExpressionError::new(ExpressionErrorKind::TooDeep, position));
+                }
+                self.chars.next();
+                if let Some((close, ')')) = self.peek() {
+                    return Err(ExpressionError::new(ExpressionErrorKind::Empty, close));
+                }
+                self.depth += 1;
+                let value = self.expression()?;
+                self.depth -= 1;
+                match self.peek() {
+                    Some((_, ')')) => {
+                        self.chars.next();
+                        Ok(value)
+                    }
+                    _ => Err(ExpressionError::new(
+                        ExpressionErrorKind::UnbalancedParenthesis,
+                        position,
+                    )),
+                }
+            }
+            Some((position, character)) if Self::is_symbol(character) => Err(ExpressionError::new(
+                ExpressionErrorKind::UnexpectedToken,
+                position,
+            )),
+            Some((start, _)) => {
+                let mut end = self.source.len();
+                while let Some(&(position, character)) = self.chars.peek() {
+                    if character.is_whitespace() || Self::is_symbol(character) {
+                        end = position;
+                        break;
+                    }
+                    self.chars.next();
+                }
+                RomanNumeral::parse(&self.source[start..end]).map_err(|err| {
+                    ExpressionError::new(
+                        ExpressionErrorKind::Numeral(err.kind()),
+                        start + err.position(),
+                    )
+                })
+            }
+        }
+    }
+
+    /// Helper function returning the next character which is not whitespace
+    fn peek(&mut self) -> Option<(usize, char)> {
+        while self.chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+        self.chars.peek().copied()
+    }
+
+    /// Helper function for the operators and parentheses which end a numeral
+    fn is_symbol(character: char) -> bool {
+        matches!(
+            character,
+            '+' | '-' | '*' | '×' | '/' | '÷' | '%' | '(' | ')'
+        )
+    }
+
+    /// Helper function to report an out of range result at its operator
+    fn in_range(
+        result: Result<RomanNumeral>,
+        position: usize,
+    ) -> Result<RomanNumeral, ExpressionError> {
+        result.map_err(|_| ExpressionError::new(ExpressionErrorKind::OutOfRange, position))
+    }
+}
@@ -0,0 +1,396 @@
+//! # kalendarium Roman fractions
+//!
+//! Roman fractions are counted in twelfths, the unciae of the as. Six twelfths are written S for
+//! semis, and each further twelfth with a dot, so S·· is 8/12. Below the uncia come the named
+//! fractions: the semuncia Σ (1/24), the sicilicus Ↄ (1/48), the sextula Ƨ (1/72), the dimidia
+//! sextula 𐆔 (1/144), the scripulum ℈ (1/288) and the siliqua 𐆕 (1/1728). A whole number is
+//! written before its fraction with nothing in between, as in IIS for 2½.
+//!
+//! https://en.wikipedia.org/wiki/Roman_numerals#Fractions
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{
+    MAX, NumeralStyle, OutOfRangeError, ParseErrorKind, ParseNumeralError, Result, RomanNumeral,
+};
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// The number of siliquae, the smallest named fraction, in one
+const SILIQUAE: u64 = 1728;
+
+/// A named Roman fraction, used as the precision when converting from rationals and floats
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum FractionUnit {
+    /// One twelfth, written ·
+    Uncia,
+    /// One twenty-fourth, written Σ
+    Semuncia,
+    /// One forty-eighth, written Ↄ
+    Sicilicus,
+    /// One seventy-second, written Ƨ
+    Sextula,
+    /// One hundred and forty-fourth, written 𐆔
+    DimidiaSextula,
+    /// One two hundred and eighty-eighth, written ℈
+    Scripulum,
+    /// One one thousand seven hundred and twenty-eighth, written 𐆕
+    Siliqua,
+}
+
+impl FractionUnit {
+    /// The number of siliquae in this fraction, e.g. 144 for the uncia.
+    #[must_use]
+    pub const fn siliquae(self) -> u32 {
+        match self {
+            FractionUnit::Uncia => 144,
+            FractionUnit::Semuncia => 72,
+            FractionUnit::Sicilicus => 36,
+            FractionUnit::Sextula => 24,
+            FractionUnit::DimidiaSextula => 12,
+            FractionUnit::Scripulum => 6,
+            FractionUnit::Siliqua => 1,
+        }
+    }
+}
+
+/// How a value is rounded to a ``FractionUnit``
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub enum Rounding {
+    /// To the nearest unit, with halves rounded up
+    #[default]
+    Nearest,
+    /// Down to the unit below
+    Down,
+    /// Up to the unit above
+    Up,
+}
+
+/// A whole ``RomanNumeral`` with a fraction in siliquae (1/1728)
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let sestertius: RomanQuantity = RomanQuantity::from_twelfths(RomanNumeral::new(2)?, 6)?;
+///    assert_eq!("IIS", sestertius.to_string());
+///
+///    let weight: RomanQuantity = "IIIS··Σ".parse()?;
+///    assert_eq!(weight.whole().as_u64(), 3);
+///    assert_eq!(weight.fraction(), 1224);
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RomanQuantity {
+    whole: RomanNumeral,
+    fraction: u16,
+}
+
+impl RomanQuantity {
+    /// Creates a ``RomanQuantity`` from a whole numeral and a fraction in siliquae.
+    /// Requires ``fraction`` to be less than 1728.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let quantity: RomanQuantity = RomanQuantity::new(RomanNumeral::new(1)?, 864)?;
+    ///    assert_eq!("IS", quantity.to_string());
+    ///
+    pub const fn new(whole: RomanNumeral, fraction: u32) -> Result<Self, OutOfRangeError> {
+        if fraction < SILIQUAE as u32 {
+            Ok(RomanQuantity {
+                whole,
+                fraction: fraction as u16,
+            })
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Creates a ``RomanQuantity`` from a whole numeral and a number of twelfths.
+    /// Requires ``unciae`` to be less than 12.
+    pub const fn from_twelfths(whole: RomanNumeral, unciae: u32) -> Result<Self, OutOfRangeError> {
+        if unciae < 12 {
+            Self::new(whole, unciae * FractionUnit::Uncia.siliquae())
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Creates a ``RomanQuantity`` from ``numerator / denominator``, rounded to ``unit``.
+    ///
+    /// Returns ``OutOfRangeError`` if the denominator is zero or the whole part is larger than
+    /// ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let third: RomanQuantity = RomanQuantity::from_ratio(1, 3, FractionUnit::Uncia, Rounding::Nearest)?;
+    ///    assert_eq!("····", third.to_string());
+    ///
+    ///    let seven: RomanQuantity = RomanQuantity::from_ratio(7, 5, FractionUnit::Uncia, Rounding::Down)?;
+    ///    assert_eq!("I··", seven.to_string());
+    ///
+    pub fn from_ratio(
+        numerator: u64,
+        denominator: u64,
+        unit: FractionUnit,
+        rounding: Rounding,
+    ) -> Result<Self, OutOfRangeError> {
+        if denominator == 0 {
+            return Err(OutOfRangeError);
+        }
+        let step = u128::from(denominator) * u128::from(unit.siliquae());
+        let scaled = u128::from(numerator) * u128::from(SILIQUAE);
+        let mut steps = scaled / step;
+        let remainder = scaled % step;
+        let round_up = match rounding {
+            Rounding::Nearest => remainder * 2 >= step,
+            Rounding::Down => false,
+            Rounding::Up => remainder > 0,
+        };
+        if round_up {
+            steps += 1;
+        }
+        let siliquae =
+            u64::try_from(steps * u128::from(unit.siliquae())).map_err(|_| OutOfRangeError)?;
+        Self::from_siliquae(siliquae)
+    }
+
+    /// Creates a ``RomanQuantity`` from a floating point ``value``, rounded to ``unit``.
+    ///
+    /// Returns ``OutOfRangeError`` for negative values, NaN, or values whose whole part is larger
+    /// than ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let measure: RomanQuantity = RomanQuantity::from_f64(2.75, FractionUnit::Uncia, Rounding::Nearest)?;
+    ///    assert_eq!("IIS···", measure.to_string());
+    ///
+    pub fn from_f64(
+        value: f64,
+        unit: FractionUnit,
+        rounding: Rounding,
+    ) -> Result<Self, OutOfRangeError> {
+        let limit = (MAX + 1) as f64;
+        if value.is_nan() || value < 0.0 || value >= limit {
+            return Err(OutOfRangeError);
+        }
+        let scaled = value * (SILIQUAE as f64) / f64::from(unit.siliquae());
+        // Truncating towards zero is the floor of a non-negative value
+        let mut steps = scaled as u64;
+        let remainder = scaled - steps as f64;
+        let round_up = match rounding {
+            Rounding::Nearest => remainder >= 0.5,
+            Rounding::Down => false,
+            Rounding::Up => remainder > 0.0,
+        };
+        if round_up {
+            steps += 1;
+        }
+        Self::from_siliquae(steps * u64::from(unit.siliquae()))
+    }
+
+    /// Helper function to create a ``RomanQuantity`` from a total number of siliquae
+    fn from_siliquae(siliquae: u64) -> Result<Self, OutOfRangeError> {
+        let whole = RomanNumeral::new(siliquae / SILIQUAE)?;
+        Ok(RomanQuantity {
+            whole,
+            fraction: (siliquae % SILIQUAE) as u16,
+        })
+    }
+
+    /// The whole part of the quantity.
+    #[must_use]
+    pub const fn whole(self) -> RomanNumeral {
+        self.whole
+    }
+
+    /// The fractional part of the quantity in siliquae (1/1728).
+    #[must_use]
+    pub const fn fraction(self) -> u32 {
+        self.fraction as u32
+    }
+
+    /// The whole twelfths of the fractional part, e.g. 8 for S··.
+    #[must_use]
+    pub const fn unciae(self) -> u32 {
+        self.fraction() / FractionUnit::Uncia.siliquae()
+    }
+
+    /// Return the value of this ``RomanQuantity`` as an ``f64``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let quantity: RomanQuantity = "IIS".parse()?;
+    ///    assert_eq!(quantity.to_f64(), 2.5);
+    ///
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        self.whole.as_u64() as f64 + f64::from(self.fraction) / SILIQUAE as f64
+    }
+
+    /// Writes a ``RomanQuantity`` to any ``fmt::Write``, with the whole part in ``style``.
+    ///
+    /// The whole part is left out when it is zero and there is a fraction, as in S for ½.
+    pub fn write_with<W: Write>(&self, style: &NumeralStyle, out: &mut W) -> fmt::Result {
+        if self.whole.as_u64() != 0 || self.fraction == 0 {
+            self.whole.write_with(style, out)?;
+        }
+        let mut rest = u32::from(self.fraction);
+        let unciae = rest / FractionUnit::Uncia.siliquae();
+        rest %= FractionUnit::Uncia.siliquae();
+        if unciae >= 6 {
+            out.write_char(if style.is_upper() { 'S' } else { 's' })?;
+        }
+        for _ in 0..unciae % 6 {
+            out.write_char('·')?;
+        }
+        for &(unit, symbol) in &SYMBOLS {
+            while rest >= unit.siliquae() {
+                out.write_char(symbol)?;
+                rest -= unit.siliquae();
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a ``RomanQuantity`` from a string.
+    ///
+    /// The whole part is parsed by ``RomanNumeral::parse`` and may be left out. The fraction is
+    /// read from S, dots (· or -), the named fractions, and the Roman fraction signs of Unicode
+    /// (U+10190–U+10195), which must be written from the largest to the smallest. What follows
+    /// the S must be less than another S, so ······ is not accepted for S.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let quantity: RomanQuantity = RomanQuantity::parse("XS--")?;
+    ///    assert_eq!(quantity.unciae(), 8);
+    ///
+    pub fn parse(s: &str) -> Result<Self, ParseNumeralError> {
+        if s.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        let split = s
+            .char_indices()
+            .find(|&(_, c)| Self::fraction_value(c).is_some())
+            .map_or(s.len(), |(position, _)| position);
+        let whole = match s[..split].trim_end() {
+            "" => RomanNumeral(0),
+            numeral => RomanNumeral::parse(numeral)?,
+        };
+
+        let mut fraction: u64 = 0;
+        let mut previous = u32::MAX;
+        let mut below_semis: u32 = 0;
+        for (position, character) in s[split..].char_indices() {
+            let position = split + position;
+            let value = match Self::fraction_value(character) {
+                Some(value) => value,
+                None => {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::InvalidCharacter,
+                        position,
+                    ));
+                }
+            };
+            // The dots of the unciae may repeat, everything else comes from largest to smallest,
+            // and what follows the S must be less than another S, so at most five dots repeat
+            if value < 864 {
+                below_semis += value;
+            }
+            if value > previous || (value == previous && value > 144) || below_semis >= 864 {
+                return Err(ParseNumeralError::new(
+                    ParseErrorKind::NonCanonical,
+                    position,
+                ));
+            }
+            previous = value;
+            fraction += u64::from(value);
+        }
+        if fraction >= SILIQUAE {
+            return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, split));
+        }
+        Ok(RomanQuantity {
+            whole,
+            fraction: fraction as u16,
+        })
+    }
+
+    /// Helper function returning the value in siliquae of a fraction symbol
+    fn fraction_value(character: char) -> Option<u32> {
+        match character {
+            'S' | 's' => Some(864),
+            '𐆐' => Some(288),
+            '·' | '-' | '𐆑' => Some(144),
+            'Σ' | '𐆒' => Some(72),
+            'Ↄ' => Some(36),
+            'Ƨ' | '𐆓' => Some(24),
+            '𐆔' => Some(12),
+            '℈' => Some(6),
+            '𐆕' => Some(1),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RomanQuantity {
+    /// Converts a ``RomanQuantity`` to an uppercase string.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let quantity: RomanQuantity = RomanQuantity::from_twelfths(RomanNumeral::new(0)?, 9)?;
+    ///    assert_eq!("S···", quantity.to_string());
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_with(&NumeralStyle::UPPERCASE, f)
+    }
+}
+
+impl FromStr for RomanQuantity {
+    type Err = ParseNumeralError;
+
+    /// Parses a ``RomanQuantity`` from a whole numeral followed by its fraction.
+    ///
+    /// Returns ``RomanQuantity`` or ``ParseNumeralError``.
+    fn from_str(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse(s)
+    }
+}
+
+impl From<RomanNumeral> for RomanQuantity {
+    /// Creates a ``RomanQuantity`` with no fraction.
+    fn from(whole: RomanNumeral) -> Self {
+        RomanQuantity { whole, fraction: 0 }
+    }
+}
+
+/// The fractions smaller than the uncia, from the largest to the smallest
+static SYMBOLS: [(FractionUnit, char); 6] = [
+    (FractionUnit::Semuncia, 'Σ'),
+    (FractionUnit::Sicilicus, 'Ↄ'),
+    (FractionUnit::Sextula, 'Ƨ'),
+    (FractionUnit::DimidiaSextula, '𐆔'),
+    (FractionUnit::Scripulum, '℈'),
+    (FractionUnit::Siliqua, '𐆕'),
+];
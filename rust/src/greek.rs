@@ -0,0 +1,526 @@
+//! # kalendarium Greek numerals
+//!
+//! The Ionic, or Milesian, numerals write numbers with the letters of the Greek alphabet, one
+//! letter for each of the units, tens and hundreds, using the archaic stigma (ϛ, 6), koppa
+//! (ϟ, 90) and sampi (ϡ, 900) for the three missing values. A keraia (ʹ) after the letters marks
+//! them as a number, e.g. ΜΒʹ for 42, and the lower left keraia (͵) before a units letter
+//! multiplies it by 1000, e.g. ͵ΑϠΠΔʹ for 1984.
+//!
+//! Tens of thousands are counted in myriads. Aristarchus wrote the number of myriads over the
+//! sign Μ, written here as the count with its keraia followed by Μ, e.g. ΡΚΓʹΜ͵ΔΦΞΖʹ for
+//! 1,234,567. Diophantus separated the myriads with a dot instead, e.g. ΡΚΓ.͵ΔΦΞΖʹ. Up to 9999
+//! myriads, or 99,999,999, are supported. There is no zero in the system, so the Greek zero sign
+//! of the astronomers (𐆊) is used for it.
+//!
+//! https://en.wikipedia.org/wiki/Greek_numerals
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{Case, OutOfRangeError, ParseErrorKind, ParseNumeralError, Result};
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// A Greek alphabetic numeral
+///
+/// Values from 0 to 99,999,999 are supported
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct GreekNumeral(pub u32);
+
+/// How myriads, the tens of thousands, are written
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Myriads {
+    /// The number of myriads with its keraia, followed by the myriad sign Μ, e.g. Βʹ Μ for
+    /// 20,000 (without the space)
+    #[default]
+    Aristarchus,
+    /// The number of myriads separated from the rest by a dot, e.g. Β.ʹ for 20,000
+    Diophantus,
+}
+
+/// A complete description of how to write out a ``GreekNumeral``
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GreekStyle {
+    case: Case,
+    myriads: Myriads,
+    keraia: bool,
+}
+
+impl GreekStyle {
+    /// The style of ``to_uppercase``
+    pub const UPPERCASE: GreekStyle = GreekStyle::new();
+    /// The style of ``to_lowercase``
+    pub const LOWERCASE: GreekStyle = GreekStyle::new().case(Case::Lower);
+
+    /// Creates the default style: uppercase, with Aristarchus' myriads and the keraia.
+    #[must_use]
+    pub const fn new() -> Self {
+        GreekStyle {
+            case: Case::Upper,
+            myriads: Myriads::Aristarchus,
+            keraia: true,
+        }
+    }
+
+    /// Sets the letter case.
+    #[must_use]
+    pub const fn case(self, case: Case) -> Self {
+        GreekStyle { case, ..self }
+    }
+
+    /// Sets how myriads are written.
+    #[must_use]
+    pub const fn myriads(self, myriads: Myriads) -> Self {
+        GreekStyle { myriads, ..self }
+    }
+
+    /// Sets whether the keraia is written after the numeral.
+    #[must_use]
+    pub const fn keraia(self, keraia: bool) -> Self {
+        GreekStyle { keraia, ..self }
+    }
+}
+
+impl Default for GreekStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GreekNumeral {
+    /// The value of the smallest Greek numeral
+    pub const MIN: u32 = 0;
+    /// The value of the largest Greek numeral, 9999 myriads and 9999
+    pub const MAX: u32 = 99_999_999;
+
+    /// Creates a ``GreekNumeral`` for any value in range.
+    /// Requires ``value`` to be less than 100,000,000. 0 (ZERO) is acceptable.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("ΜΒʹ", answer.to_uppercase());
+    ///
+    pub const fn new(value: u32) -> Result<Self, OutOfRangeError> {
+        if value <= Self::MAX {
+            Ok(GreekNumeral(value))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Return the value of this ``GreekNumeral`` as a ``u32``.
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Converts a ``GreekNumeral`` to an uppercase string.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: GreekNumeral = GreekNumeral::new(1984)?;
+    ///    assert_eq!("͵ΑϠΠΔʹ", year.to_uppercase());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_uppercase(self) -> String {
+        self.format_with(&GreekStyle::UPPERCASE)
+    }
+
+    /// Converts a ``GreekNumeral`` to a lowercase string.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: GreekNumeral = GreekNumeral::new(1984)?;
+    ///    assert_eq!("͵αϡπδʹ", year.to_lowercase());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_lowercase(self) -> String {
+        self.format_with(&GreekStyle::LOWERCASE)
+    }
+
+    /// Converts a ``GreekNumeral`` to a string written in ``style``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let style = GreekStyle::new().myriads(Myriads::Diophantus);
+    ///    let big: GreekNumeral = GreekNumeral::new(1_234_567)?;
+    ///    assert_eq!("ΡΚΓ.͵ΔΦΞΖʹ", big.format_with(&style));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format_with(self, style: &GreekStyle) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_with(style, &mut out);
+        out
+    }
+
+    /// Writes a ``GreekNumeral`` in ``style`` to any ``fmt::Write``, without allocating.
+    pub fn write_with<W: Write>(&self, style: &GreekStyle, out: &mut W) -> fmt::Result {
+        if self.0 == 0 {
+            return out.write_char(ZERO);
+        }
+        let myriads = self.0 / MYRIAD;
+        let rest = self.0 % MYRIAD;
+        if myriads > 0 {
+            Self::write_group(myriads, style, out)?;
+            match style.myriads {
+                Myriads::Aristarchus => {
+                    out.write_char(KERAIA)?;
+                    out.write_char(MYRIAD_SIGN)?;
+                }
+                Myriads::Diophantus => out.write_char('.')?,
+            }
+        }
+        if rest > 0 {
+            Self::write_group(rest, style, out)?;
+        }
+        if style.keraia && (rest > 0 || style.myriads == Myriads::Diophantus) {
+            out.write_char(KERAIA)?;
+        }
+        Ok(())
+    }
+
+    /// Helper function to write a number below 10,000 with a letter for each decimal place
+    fn write_group<W: Write>(num: u32, style: &GreekStyle, out: &mut W) -> fmt::Result {
+        let letters = match style.case {
+            Case::Upper => &UPPERCASE,
+            Case::Lower => &LOWERCASE,
+        };
+        let thousands = num / 1000;
+        if thousands > 0 {
+            out.write_char(LOWER_KERAIA)?;
+            out.write_char(letters[0][thousands as usize - 1])?;
+        }
+        for (place, power) in [(2, 100), (1, 10), (0, 1)] {
+            let digit = num / power % 10;
+            if digit > 0 {
+                out.write_char(letters[place][digit as usize - 1])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a ``GreekNumeral`` from a string.
+    ///
+    /// The letters must be in a single case and in canonical order, from the thousands to the
+    /// units. The keraia may be U+02B9, U+0374 or an ASCII apostrophe, and may be left out at the
+    /// end. Both Aristarchus' and Diophantus' myriads are accepted, as is the archaic koppa (Ϙ).
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::parse("ΜΒʹ")?;
+    ///    assert_eq!(answer.as_u32(), 42);
+    ///
+    ///    let big: GreekNumeral = "ρκγʹΜ͵δφξζ".parse()?;
+    ///    assert_eq!(big.as_u32(), 1_234_567);
+    ///
+    pub fn parse(s: &str) -> Result<Self, ParseNumeralError> {
+        if s.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        if s.chars().eq([ZERO]) {
+            return Ok(GreekNumeral(0));
+        }
+        let body = match s.char_indices().next_back() {
+            Some((position, last)) if Self::is_keraia(last) => &s[..position],
+            _ => s,
+        };
+
+        let mut uppercase: Option<bool> = None;
+        let aristarchus = body.char_indices().find(|&(position, character)| {
+            Self::is_keraia(character) && body[position..].chars().nth(1) == Some(MYRIAD_SIGN)
+        });
+        let (myriads, rest, offset) = match aristarchus {
+            Some((position, keraia)) => {
+                let start = position + keraia.len_utf8() + MYRIAD_SIGN.len_utf8();
+                (Some(&body[..position]), &body[start..], start)
+            }
+            None => match body.find('.') {
+                Some(position) => (Some(&body[..position]), &body[position + 1..], position + 1),
+                None => (None, body, 0),
+            },
+        };
+
+        let mut value = 0;
+        if let Some(myriads) = myriads {
+            let count = Self::parse_group(myriads, 0, &mut uppercase)?;
+            if count == 0 {
+                return Err(ParseNumeralError::new(ParseErrorKind::NonCanonical, 0));
+            }
+            value = count * MYRIAD;
+        } else if rest.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        value += Self::parse_group(rest, offset, &mut uppercase)?;
+        Ok(GreekNumeral(value))
+    }
+
+    /// Helper function to parse the letters of a number below 10,000 at byte ``offset``
+    fn parse_group(
+        s: &str,
+        offset: usize,
+        uppercase: &mut Option<bool>,
+    ) -> Result<u32, ParseNumeralError> {
+        let mut value = 0;
+        // Each place may be written once, from the thousands (3) down to the units (0)
+        let mut next_place = 4;
+        let mut chars = s.char_indices();
+        while let Some((position, character)) = chars.next() {
+            let thousands = character == LOWER_KERAIA;
+            let (position, character) = match thousands.then(|| chars.next()) {
+                None => (position, character),
+                Some(Some(next)) => next,
+                Some(None) => {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::NonCanonical,
+                        offset + s.len(),
+                    ));
+                }
+            };
+            let position = offset + position;
+            let (mut place, digit) = match Self::letter_value(character) {
+                Some(letter) => letter,
+                None => {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::InvalidCharacter,
+                        position,
+                    ));
+                }
+            };
+            // The lower left keraia only multiplies a units letter
+            if thousands && place == 0 {
+                place = 3;
+            }
+            let is_upper = character.is_uppercase();
+            if place >= next_place
+                || thousands != (place == 3)
+                || uppercase.is_some_and(|case| case != is_upper)
+            {
+                return Err(ParseNumeralError::new(
+                    ParseErrorKind::NonCanonical,
+                    position,
+                ));
+            }
+            *uppercase = Some(is_upper);
+            value += digit * 10_u32.pow(place);
+            next_place = place;
+        }
+        Ok(value)
+    }
+
+    /// Helper function returning the decimal place and digit of a letter, e.g. (1, 4) for Μ
+    fn letter_value(character: char) -> Option<(u32, u32)> {
+        if matches!(character, 'Ϙ' | 'ϙ') {
+            return Some((1, 9));
+        }
+        for (place, letters) in UPPERCASE.iter().chain(LOWERCASE.iter()).enumerate() {
+            if let Some(index) = letters.iter().position(|&letter| letter == character) {
+                return Some(((place % 3) as u32, index as u32 + 1));
+            }
+        }
+        None
+    }
+
+    /// Helper function for the characters accepted as a keraia
+    fn is_keraia(character: char) -> bool {
+        matches!(character, KERAIA | '\u{374}' | '\'')
+    }
+}
+
+impl fmt::Display for GreekNumeral {
+    /// Converts a ``GreekNumeral`` to an uppercase string.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("ΜΒʹ", answer.to_string());
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_with(&GreekStyle::UPPERCASE, f)
+    }
+}
+
+impl FromStr for GreekNumeral {
+    type Err = ParseNumeralError;
+
+    /// Parses a ``GreekNumeral`` from its uppercase or lowercase string.
+    ///
+    /// Returns ``GreekNumeral`` or ``ParseNumeralError``.
+    fn from_str(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse(s)
+    }
+}
+
+/// The value of a myriad
+const MYRIAD: u32 = 10_000;
+
+/// The keraia, which marks letters as a number (U+02B9, the canonical form of U+0374)
+const KERAIA: char = '\u{2b9}';
+
+/// The lower left keraia, which multiplies the following letter by 1000
+const LOWER_KERAIA: char = '\u{375}';
+
+/// The sign of the myriad, written after the number of myriads
+const MYRIAD_SIGN: char = 'Μ';
+
+/// The Greek zero sign
+const ZERO: char = '\u{1018a}';
+
+/// The uppercase letters for one to nine of the units, tens and hundreds
+static UPPERCASE: [[char; 9]; 3] = [
+    ['Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ϛ', 'Ζ', 'Η', 'Θ'],
+    ['Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ϟ'],
+    ['Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'Ϡ'],
+];
+
+/// The lowercase letters for one to nine of the units, tens and hundreds
+static LOWERCASE: [[char; 9]; 3] = [
+    ['α', 'β', 'γ', 'δ', 'ε', 'ϛ', 'ζ', 'η', 'θ'],
+    ['ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ϟ'],
+    ['ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', 'ϡ'],
+];
+
+impl TryFrom<u8> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u8``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u8) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u16> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u16``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u32> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u32``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<u64> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u64``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<u128> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u128``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<usize> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``usize``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i8> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``i8``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i8) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i16> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``i16``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i16) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i32> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``i32``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i32) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i64> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``i64``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i128> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``i128``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
@@ -0,0 +1,453 @@
+//! # kalendarium Hebrew numerals
+//!
+//! The Hebrew alphabetic numerals, as used for years, chapters and verses, give each of the 22
+//! letters a value: א to ט for the units, י to צ for the tens and ק to ת for the hundreds 100 to
+//! 400. Larger hundreds are written by adding ת, e.g. תק for 500 and תתק for 900, or with the
+//! five final letters ך ם ן ף ץ for 500 to 900. Fifteen and sixteen are written טו and טז
+//! (9 + 6 and 9 + 7) instead of יה and יו, which spell the name of God.
+//!
+//! A geresh (׳) after a single letter, or a gershayim (״) before the last of several letters,
+//! marks them as a number, e.g. ה׳ for 5 and תשפ״ד for 784. The thousands are counted with a
+//! geresh after them, e.g. ה׳תשפ״ד for 5784, and a whole number of thousands is followed by the
+//! word אלפים, e.g. ה׳ אלפים for 5000. Values from 1 to 999,999 are supported.
+//!
+//! https://en.wikipedia.org/wiki/Hebrew_numerals
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::numeral::Canonical;
+use crate::{OutOfRangeError, ParseErrorKind, ParseNumeralError, Result};
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// A Hebrew alphabetic numeral
+///
+/// Values from 1 to 999,999 are supported
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct HebrewNumeral(pub u32);
+
+/// A complete description of how to write out a ``HebrewNumeral``
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct HebrewStyle {
+    punctuation: bool,
+    final_forms: bool,
+}
+
+impl HebrewStyle {
+    /// Creates the default style: with the geresh and gershayim, and ת for the hundreds from 500.
+    #[must_use]
+    pub const fn new() -> Self {
+        HebrewStyle {
+            punctuation: true,
+            final_forms: false,
+        }
+    }
+
+    /// Sets whether the geresh or gershayim marks the letters as a number. The geresh after the
+    /// thousands is always written.
+    #[must_use]
+    pub const fn punctuation(self, punctuation: bool) -> Self {
+        HebrewStyle {
+            punctuation,
+            ..self
+        }
+    }
+
+    /// Sets whether the final letters ך ם ן ף ץ are written for 500 to 900.
+    #[must_use]
+    pub const fn final_forms(self, final_forms: bool) -> Self {
+        HebrewStyle {
+            final_forms,
+            ..self
+        }
+    }
+}
+
+impl Default for HebrewStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HebrewNumeral {
+    /// The value of the smallest Hebrew numeral
+    pub const MIN: u32 = 1;
+    /// The value of the largest Hebrew numeral
+    pub const MAX: u32 = 999_999;
+
+    /// Creates a ``HebrewNumeral`` for any value in range.
+    /// Requires ``value`` to be between 1 and 999,999, as there is no Hebrew zero.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: HebrewNumeral = HebrewNumeral::new(5784)?;
+    ///    assert_eq!("ה׳תשפ״ד", year.to_string());
+    ///
+    pub const fn new(value: u32) -> Result<Self, OutOfRangeError> {
+        if value >= Self::MIN && value <= Self::MAX {
+            Ok(HebrewNumeral(value))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Return the value of this ``HebrewNumeral`` as a ``u32``.
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Converts a ``HebrewNumeral`` to a string written in ``style``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let style = HebrewStyle::new().punctuation(false).final_forms(true);
+    ///    let num: HebrewNumeral = HebrewNumeral::new(616)?;
+    ///    assert_eq!("םטז", num.format_with(&style));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format_with(self, style: &HebrewStyle) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_with(style, &mut out);
+        out
+    }
+
+    /// Writes a ``HebrewNumeral`` in ``style`` to any ``fmt::Write``, without allocating.
+    pub fn write_with<W: Write>(&self, style: &HebrewStyle, out: &mut W) -> fmt::Result {
+        let thousands = self.0 / 1000;
+        let rest = self.0 % 1000;
+        if thousands > 0 {
+            for letter in Self::letters(thousands, style).iter().flatten() {
+                out.write_char(*letter)?;
+            }
+            out.write_char(GERESH)?;
+            if rest == 0 {
+                return out.write_str(THOUSANDS);
+            }
+        }
+        let letters = Self::letters(rest, style);
+        let count = letters.iter().flatten().count();
+        for (index, letter) in letters.iter().flatten().enumerate() {
+            if style.punctuation && count > 1 && index == count - 1 {
+                out.write_char(GERSHAYIM)?;
+            }
+            out.write_char(*letter)?;
+        }
+        if style.punctuation && count == 1 {
+            out.write_char(GERESH)?;
+        }
+        Ok(())
+    }
+
+    /// Helper function returning the letters of a number below 1000, from the largest
+    fn letters(num: u32, style: &HebrewStyle) -> [Option<char>; 5] {
+        let mut letters = [None; 5];
+        let mut count = 0;
+        let mut push = |letter: char| {
+            letters[count] = Some(letter);
+            count += 1;
+        };
+        let hundreds = num / 100;
+        if style.final_forms && hundreds >= 5 {
+            push(FINALS[hundreds as usize - 5]);
+        } else {
+            for _ in 0..hundreds / 4 {
+                push('ת');
+            }
+            let rest = hundreds % 4;
+            if rest > 0 {
+                push(HUNDREDS[rest as usize - 1]);
+            }
+        }
+        match num % 100 {
+            15 => ['ט', 'ו'].into_iter().for_each(&mut push),
+            16 => ['ט', 'ז'].into_iter().for_each(&mut push),
+            rest => {
+                let (tens, units) = (rest / 10, rest % 10);
+                if tens > 0 {
+                    push(TENS[tens as usize - 1]);
+                }
+                if units > 0 {
+                    push(UNITS[units as usize - 1]);
+                }
+            }
+        }
+        letters
+    }
+
+    /// Parses a ``HebrewNumeral`` from a string.
+    ///
+    /// Only the canonical forms are accepted, with or without the geresh and gershayim, and with
+    /// or without the final letters for 500 to 900. The ASCII apostrophe and quotation mark may be
+    /// used for the geresh and gershayim.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let year: HebrewNumeral = HebrewNumeral::parse("ה'תשפ\"ד")?;
+    ///    assert_eq!(year.as_u32(), 5784);
+    ///
+    pub fn parse(s: &str) -> Result<Self, ParseNumeralError> {
+        if s.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        // A whole number of thousands is followed by a word, whose letters are not counted
+        let (letters, multiplier) = match s.strip_suffix(THOUSANDS) {
+            Some(thousands) => (thousands, 1000),
+            None => (s, 1),
+        };
+        let mut value: u32 = 0;
+        let mut group: u32 = 0;
+        let mut chars = letters.char_indices().peekable();
+        while let Some((position, character)) = chars.next() {
+            match Self::normalise(character) {
+                GERESH if chars.peek().is_some() => {
+                    value = group.saturating_mul(1000);
+                    group = 0;
+                }
+                GERESH | GERSHAYIM => (),
+                _ => match Self::letter_value(character) {
+                    Some(letter) => group = group.saturating_add(letter),
+                    None => {
+                        return Err(ParseNumeralError::new(
+                            ParseErrorKind::InvalidCharacter,
+                            position,
+                        ));
+                    }
+                },
+            }
+        }
+        let value = value.saturating_add(group).saturating_mul(multiplier);
+        if value > Self::MAX {
+            return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0));
+        }
+        if value == 0 {
+            return Err(ParseNumeralError::new(ParseErrorKind::NonCanonical, 0));
+        }
+
+        // Compare against the canonical form with and without the geresh and gershayim
+        let mut matched = 0;
+        for punctuation in [true, false] {
+            let style = HebrewStyle::new()
+                .punctuation(punctuation)
+                .final_forms(letters.contains(FINALS));
+            let mut canonical = Canonical::normalised(s, HebrewNumeral::normalise);
+            let result = HebrewNumeral(value).write_with(&style, &mut canonical);
+            if result.is_ok() && canonical.is_complete() {
+                return Ok(HebrewNumeral(value));
+            }
+            matched = matched.max(canonical.matched);
+        }
+        Err(ParseNumeralError::new(
+            ParseErrorKind::NonCanonical,
+            matched,
+        ))
+    }
+
+    /// Helper function returning the value of a letter
+    fn letter_value(character: char) -> Option<u32> {
+        let value = match character {
+            'א'..='ט' => UNITS.iter().position(|&letter| letter == character)? + 1,
+            'ך' => 500,
+            'ם' => 600,
+            'ן' => 700,
+            'ף' => 800,
+            'ץ' => 900,
+            'י'..='צ' => (TENS.iter().position(|&letter| letter == character)? + 1) * 10,
+            'ק'..='ת' => (HUNDREDS.iter().position(|&letter| letter == character)? + 1) * 100,
+            _ => return None,
+        };
+        u32::try_from(value).ok()
+    }
+
+    /// Helper function to read the ASCII apostrophe and quotation mark as geresh and gershayim
+    const fn normalise(character: char) -> char {
+        match character {
+            '\'' => GERESH,
+            '"' => GERSHAYIM,
+            _ => character,
+        }
+    }
+}
+
+impl fmt::Display for HebrewNumeral {
+    /// Converts a ``HebrewNumeral`` to a string with the geresh and gershayim.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let verse: HebrewNumeral = HebrewNumeral::new(15)?;
+    ///    assert_eq!("ט״ו", verse.to_string());
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_with(&HebrewStyle::new(), f)
+    }
+}
+
+impl FromStr for HebrewNumeral {
+    type Err = ParseNumeralError;
+
+    /// Parses a ``HebrewNumeral`` from its canonical string.
+    ///
+    /// Returns ``HebrewNumeral`` or ``ParseNumeralError``.
+    fn from_str(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse(s)
+    }
+}
+
+/// The geresh, written after a single letter and after the thousands
+const GERESH: char = '\u{5f3}';
+
+/// The gershayim, written before the last of several letters
+const GERSHAYIM: char = '\u{5f4}';
+
+/// The word for thousands, written after a whole number of thousands
+const THOUSANDS: &str = " אלפים";
+
+/// The letters for one to nine
+static UNITS: [char; 9] = ['א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט'];
+
+/// The letters for ten to ninety
+static TENS: [char; 9] = ['י', 'כ', 'ל', 'מ', 'נ', 'ס', 'ע', 'פ', 'צ'];
+
+/// The letters for one hundred to four hundred
+static HUNDREDS: [char; 4] = ['ק', 'ר', 'ש', 'ת'];
+
+/// The final letters for five hundred to nine hundred
+static FINALS: [char; 5] = ['ך', 'ם', 'ן', 'ף', 'ץ'];
+
+impl TryFrom<u8> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``u8``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u8) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u16> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``u16``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u32> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``u32``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<u64> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``u64``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<u128> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``u128``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<usize> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``usize``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i8> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``i8``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i8) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i16> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``i16``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i16) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i32> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``i32``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i32) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i64> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``i64``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
+
+impl TryFrom<i128> for HebrewNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``HebrewNumeral`` from an ``i128``.
+    ///
+    /// Returns ``HebrewNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    }
+}
@@ -0,0 +1,348 @@
+//! # kalendarium lenient Roman numeral parsing
+//!
+//! Real-world numerals, especially in manuscripts and transcriptions, are rarely written in the
+//! canonical form that ``RomanNumeral::parse`` insists on. This module reads them anyway and
+//! reports every way in which they deviate from the canonical form, so that an editor can flag
+//! and normalise them.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{
+    LargeNumerals, Letters, MAX, NumeralStyle, ParseErrorKind, ParseNumeralError, Result,
+    RomanNumeral,
+};
+use core::ops::Range;
+
+/// A Roman numeral read by ``RomanNumeral::parse_lenient``, along with its deviations from the
+/// canonical form
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LenientNumeral {
+    /// The value of the numeral
+    pub value: RomanNumeral,
+    /// Every deviation from the canonical form, in the order they appear in the input
+    pub deviations: Vec<Deviation>,
+}
+
+impl LenientNumeral {
+    /// Whether the input was already in canonical form.
+    #[must_use]
+    pub fn is_canonical(&self) -> bool {
+        self.deviations.is_empty()
+    }
+}
+
+/// A single deviation from the canonical form of a Roman numeral
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Deviation {
+    /// What kind of deviation this is
+    pub kind: DeviationKind,
+    /// The byte range of the input where the deviation was found
+    pub span: Range<usize>,
+}
+
+/// The kinds of non-canonical spellings accepted by ``RomanNumeral::parse_lenient``
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum DeviationKind {
+    /// Four of a kind written out rather than subtracted, e.g. ``IIII`` or ``VIIII``
+    Additive,
+    /// A subtraction the canonical form does not use, e.g. ``IIX`` or ``IC``
+    IrregularSubtractive,
+    /// ``j`` written for ``i``, as in the medieval terminal ``iij``
+    LetterJ,
+    /// Five written with ``U`` in an uppercase numeral, or ``v`` in a lowercase one
+    LetterU,
+    /// A letter whose case differs from the first letter of the numeral
+    MixedCase,
+    /// A vinculum written with a macron or a leading ASCII underscore instead of U+0305, or a
+    /// double vinculum written with two bars instead of U+033F
+    OverbarSubstitute,
+    /// Any other spelling which differs from the canonical form, e.g. ``VV`` or ``LXL``
+    NonCanonical,
+}
+
+/// A single numeral letter read from the input
+struct Symbol {
+    value: u64,
+    letter: char,
+    bars: u8,
+    framed: bool,
+    span: Range<usize>,
+}
+
+impl RomanNumeral {
+    /// Parses a ``RomanNumeral`` from a string, accepting historical and non-canonical spellings.
+    ///
+    /// Accepted are additive forms (``IIII``, ``VIIII``), ``j`` for ``i`` (``iij``), ``u`` and
+    /// ``v`` used interchangeably, mixed case, irregular subtraction (``IIX``, ``IC``), and a
+    /// vinculum written either with U+0305, a combining (U+0304) or spacing (U+00AF) macron after
+    /// the letter, or an ASCII underscore before it (``_V`` for 5000). A double vinculum may be
+    /// written with U+033F or with two bars, and framed numerals (``|X̅|`` for 1,000,000) are read
+    /// as well. Every such deviation is returned along with its byte span in the input.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: LenientNumeral = RomanNumeral::parse_lenient("xiiij")?;
+    ///    assert_eq!(num.value.as_u64(), 14_u64);
+    ///    assert_eq!(num.deviations[0].kind, DeviationKind::Additive);
+    ///    assert_eq!(num.deviations[1].kind, DeviationKind::LetterJ);
+    ///
+    pub fn parse_lenient(s: &str) -> Result<LenientNumeral, ParseNumeralError> {
+        let mut deviations: Vec<Deviation> = Vec::new();
+        let trimmed = s.trim_end();
+        if trimmed.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        if trimmed.eq_ignore_ascii_case("N") || trimmed.eq_ignore_ascii_case("nulla") {
+            if trimmed != "N" {
+                deviations.push(Deviation {
+                    kind: DeviationKind::NonCanonical,
+                    span: 0..trimmed.len(),
+                });
+            }
+            return Ok(LenientNumeral {
+                value: RomanNumeral(0),
+                deviations,
+            });
+        }
+
+        let symbols = Self::lenient_symbols(trimmed, &mut deviations)?;
+
+        // Group runs of the same value, e.g. the three Xs of XXX
+        let mut groups: Vec<(u64, Range<usize>, usize)> = Vec::new();
+        for symbol in &symbols {
+            match groups.last_mut() {
+                Some((value, span, count)) if *value == symbol.value => {
+                    span.end = symbol.span.end;
+                    *count += 1;
+                }
+                _ => groups.push((symbol.value, symbol.span.clone(), 1)),
+            }
+        }
+
+        let mut total: i64 = 0;
+        let mut structural = false;
+        for (index, (value, span, count)) in groups.iter().enumerate() {
+            let amount = i64::try_from(*value)
+                .unwrap_or(i64::MAX)
+                .saturating_mul(*count as i64);
+            match groups.get(index + 1) {
+                Some((next, next_span, _)) if next > value => {
+                    total -= amount;
+                    let ratio = next / value;
+                    if *count > 1 || (ratio != 5 && ratio != 10) || !Self::is_power_of_ten(*value) {
+                        deviations.push(Deviation {
+                            kind: DeviationKind::IrregularSubtractive,
+                            span: span.start..next_span.end,
+                        });
+                        structural = true;
+                    }
+                }
+                _ => {
+                    total = total.saturating_add(amount);
+                    // The billions have nothing larger to subtract from, and the millions are
+                    // written additively by default, as in M̅M̅M̅M̅
+                    if *count >= 4 && !matches!(*value, 1_000_000 | 1_000_000_000) {
+                        deviations.push(Deviation {
+                            kind: DeviationKind::Additive,
+                            span: span.clone(),
+                        });
+                        structural = true;
+                    }
+                }
+            }
+        }
+
+        let value = match u64::try_from(total) {
+            Ok(number) if number <= MAX => RomanNumeral(number),
+            Ok(_) => return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0)),
+            Err(_) if total > 0 => {
+                return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0));
+            }
+            Err(_) => return Err(ParseNumeralError::new(ParseErrorKind::NonCanonical, 0)),
+        };
+
+        // Catch anything the checks above cannot name, such as VV or an unnecessary XM
+        if !structural {
+            let mut normalized = String::new();
+            let mut framed = false;
+            for symbol in &symbols {
+                if symbol.framed != framed {
+                    normalized.push('|');
+                    framed = symbol.framed;
+                }
+                normalized.push(symbol.letter);
+                match symbol.bars {
+                    0 => {}
+                    1 => normalized.push('\u{305}'),
+                    _ => normalized.push('\u{33f}'),
+                }
+            }
+            if framed {
+                normalized.push('|');
+            }
+            let mut style = NumeralStyle::UPPERCASE;
+            let framed = symbols.iter().any(|symbol| symbol.framed);
+            if framed {
+                style = style.large(LargeNumerals::Framed);
+            }
+            if symbols
+                .iter()
+                .any(|symbol| Self::is_number_form(symbol.letter))
+            {
+                style = style.letters(Letters::NumberForms);
+            }
+            // The millions may subtract with the double vinculum, as in I̿V̿ for M̅M̅M̅M̅
+            let double = style.large(LargeNumerals::DoubleVinculum);
+            if symbols.iter().any(|symbol| symbol.bars > 2)
+                || (Ok(&normalized) != value.format_with(&style).as_ref()
+                    && (framed || Ok(&normalized) != value.format_with(&double).as_ref()))
+            {
+                deviations.push(Deviation {
+                    kind: DeviationKind::NonCanonical,
+                    span: 0..trimmed.len(),
+                });
+            }
+        }
+        deviations.sort_by_key(|deviation| (deviation.span.start, deviation.kind));
+
+        Ok(LenientNumeral { value, deviations })
+    }
+
+    /// Helper function which reads the numeral letters of ``s``, recording spelling deviations
+    fn lenient_symbols(
+        s: &str,
+        deviations: &mut Vec<Deviation>,
+    ) -> Result<Vec<Symbol>, ParseNumeralError> {
+        let mut symbols: Vec<Symbol> = Vec::new();
+        let mut uppercase: Option<bool> = None;
+        let mut frame = false;
+        let mut chars = s.char_indices().peekable();
+        while let Some((position, character)) = chars.next() {
+            if character == '|' {
+                frame = !frame;
+                continue;
+            }
+            let start = position;
+            let mut letter = character;
+            let mut underscores: u8 = 0;
+            let mut letter_position = position;
+            while letter == '_' {
+                match chars.next() {
+                    Some((next_position, next)) => {
+                        letter = next;
+                        letter_position = next_position;
+                    }
+                    None => {
+                        return Err(ParseNumeralError::new(
+                            ParseErrorKind::InvalidCharacter,
+                            position,
+                        ));
+                    }
+                }
+                underscores = underscores.saturating_add(1);
+            }
+            let mut substitute = underscores > 0;
+            let mut end = letter_position + letter.len_utf8();
+            let value = match letter {
+                'J' | 'j' => {
+                    deviations.push(Deviation {
+                        kind: DeviationKind::LetterJ,
+                        span: start..end,
+                    });
+                    1
+                }
+                'U' | 'u' | 'V' | 'v' => 5,
+                other => match Self::symbol_value(other) {
+                    Some(value) => value,
+                    None => {
+                        return Err(ParseNumeralError::new(
+                            ParseErrorKind::InvalidCharacter,
+                            letter_position,
+                        ));
+                    }
+                },
+            };
+
+            // ↁ and the other large Number Forms have no case
+            let is_upper = letter.is_uppercase();
+            match uppercase {
+                _ if !is_upper && !letter.is_lowercase() => {}
+                None => uppercase = Some(is_upper),
+                Some(case) if case != is_upper => deviations.push(Deviation {
+                    kind: DeviationKind::MixedCase,
+                    span: start..end,
+                }),
+                Some(_) => {}
+            }
+            let case = uppercase.unwrap_or(true);
+            if (letter == 'U' && case) || (letter == 'v' && !case) {
+                deviations.push(Deviation {
+                    kind: DeviationKind::LetterU,
+                    span: start..end,
+                });
+            }
+
+            let mut bars = underscores;
+            let mut marks = 0;
+            while let Some((mark, overbar)) = chars.next_if(|&(_, c)| Self::is_overbar(c)) {
+                if overbar == '\u{33f}' {
+                    bars = bars.saturating_add(2);
+                } else {
+                    bars = bars.saturating_add(1);
+                }
+                if overbar != '\u{305}' && overbar != '\u{33f}' {
+                    substitute = true;
+                }
+                marks += 1;
+                end = mark + overbar.len_utf8();
+            }
+            // A double vinculum is written with the single mark U+033F
+            if marks > 1 || (marks > 0 && underscores > 0) {
+                substitute = true;
+            }
+            if substitute {
+                deviations.push(Deviation {
+                    kind: DeviationKind::OverbarSubstitute,
+                    span: start..end,
+                });
+            }
+            // Inside a frame the first bar belongs to the frame, which multiplies by 100,000
+            let mut scale: u64 = if frame { 100_000 } else { 1 };
+            scale = scale.saturating_mul(
+                1000_u64.saturating_pow(u32::from(bars.saturating_sub(u8::from(frame)))),
+            );
+            symbols.push(Symbol {
+                value: u64::from(value).saturating_mul(scale),
+                letter: match letter {
+                    'J' | 'j' => 'I',
+                    'U' | 'u' => 'V',
+                    other => other.to_uppercase().next().unwrap_or(other),
+                },
+                bars,
+                framed: frame,
+                span: start..end,
+            });
+        }
+        Ok(symbols)
+    }
+
+    /// Helper function for the marks accepted as a vinculum over the preceding letter
+    fn is_overbar(character: char) -> bool {
+        matches!(character, '\u{305}' | '\u{33f}' | '\u{304}' | '\u{af}')
+    }
+
+    /// Helper function to test whether a symbol can be subtracted, i.e. is I, X, C, M, etc.
+    fn is_power_of_ten(value: u64) -> bool {
+        let mut power = 1;
+        while power < value {
+            power *= 10;
+        }
+        power == value
+    }
+}
@@ -3,7 +3,7 @@
 //! This rust library contains modules for converting Arabic numerals into Roman numerals, and for
 //! converting dates into the Kalendarium Romanum.
 //!
-//! The largest valid numeral is 9,999,999. Though the Romans did not have an explicit symbol for 0
+//! The largest valid numeral is 4,999,999,999. Though the Romans did not have an explicit symbol for 0
 //! (ZERO), this library employs nulla (N) as zero, always uppercase.
 //!
 //! Numbers 4000 and larger are represented using the vinculum system, wereby a bar over symbols,
@@ -16,18 +16,73 @@
 #![warn(clippy::print_stderr)]
 #![warn(clippy::print_stdout)]
 
+mod additive;
+mod apostrophus;
+mod arithmetic;
+mod attic;
+#[cfg(feature = "std")]
+mod chronogram;
+mod counter;
+mod cyrillic;
+#[cfg(feature = "std")]
 mod date;
 mod error;
+mod etruscan;
+mod expression;
+mod fraction;
+mod greek;
+mod hebrew;
+#[cfg(feature = "std")]
+mod lenient;
+mod manuscript;
+mod markup;
 mod numeral;
+#[cfg(feature = "std")]
+mod scan;
+#[cfg(feature = "std")]
+mod sort;
+mod style;
+#[cfg(feature = "std")]
+mod svg;
+mod system;
+mod words;
 
+pub use additive::AdditiveForms;
+pub use apostrophus::Apostrophus;
+pub use attic::{AtticNumeral, AtticUnit};
+#[cfg(feature = "std")]
+pub use chronogram::{Chronogram, ChronogramLetter, ChronogramRules};
+pub use counter::{CounterStyle, Outline};
+pub use cyrillic::{CyrillicNumeral, CyrillicStyle};
+#[cfg(feature = "std")]
 pub use date::Kalendarium;
-pub use error::OutOfRangeError;
+pub use error::{
+    ExpressionError, ExpressionErrorKind, OutOfRangeError, ParseErrorKind, ParseFormatError,
+    ParseNumeralError,
+};
+pub use etruscan::{Direction, EtruscanNumeral};
+pub use fraction::{FractionUnit, RomanQuantity, Rounding};
+pub use greek::{GreekNumeral, GreekStyle, Myriads};
+pub use hebrew::{HebrewNumeral, HebrewStyle};
+#[cfg(feature = "std")]
+pub use lenient::{Deviation, DeviationKind, LenientNumeral};
+pub use manuscript::Manuscript;
+pub use markup::Markup;
 pub use numeral::RomanNumeral;
+#[cfg(feature = "std")]
+pub use scan::{NumeralMatch, NumeralScanner, ScanContext};
+#[cfg(feature = "std")]
+pub use sort::SortKey;
+pub use style::{Case, LargeNumerals, Letters, NumeralStyle, Overbar, VLetter, Zero};
+#[cfg(feature = "std")]
+pub use svg::Inscription;
+pub use system::{NumeralFormat, NumeralSystem};
+pub use words::{Gender, GrammaticalCase, LatinWords, WordKind};
 
 /// The value of the smallest Roman numeral
-pub const MIN: u32 = 0;
+pub const MIN: u64 = 0;
 /// The value of the largest Roman numeral
-pub const MAX: u32 = 4_999_999;
+pub const MAX: u64 = 4_999_999_999;
 
 /// [`Result`](std::result::Result) with error defaulted to [`kalendarium::OutOfRangeError`](Error)
 pub type Result<T, E = OutOfRangeError> = core::result::Result<T, E>;
@@ -39,33 +94,40 @@ mod test {
 
     #[test]
     fn test_roman_numeral_new() {
-        assert_eq!(RomanNumeral::new(0), Ok(RomanNumeral(0_u32)));
-        assert_eq!(RomanNumeral::new(1), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::new(1_u8.into()), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::new(1_u32), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::new(42), Ok(RomanNumeral(42_u32)));
-        assert_eq!(RomanNumeral::new(616), Ok(RomanNumeral(616_u32)));
-        assert_eq!(RomanNumeral::new(49_999), Ok(RomanNumeral(49_999_u32)));
-        assert_eq!(RomanNumeral::new(99_999), Ok(RomanNumeral(99_999_u32)));
-        assert_eq!(RomanNumeral::new(999_999), Ok(RomanNumeral(999_999_u32)));
-        assert_eq!(RomanNumeral::new(MAX), Ok(RomanNumeral(4_999_999_u32)));
-        assert!(matches!(RomanNumeral::new(5_000_000), Err(OutOfRangeError)));
-        assert!(matches!(RomanNumeral::new(u32::MAX), Err(OutOfRangeError)));
+        assert_eq!(RomanNumeral::new(0), Ok(RomanNumeral(0_u64)));
+        assert_eq!(RomanNumeral::new(1), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::new(1_u8.into()), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::new(1_u64), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::new(42), Ok(RomanNumeral(42_u64)));
+        assert_eq!(RomanNumeral::new(616), Ok(RomanNumeral(616_u64)));
+        assert_eq!(RomanNumeral::new(49_999), Ok(RomanNumeral(49_999_u64)));
+        assert_eq!(RomanNumeral::new(99_999), Ok(RomanNumeral(99_999_u64)));
+        assert_eq!(RomanNumeral::new(999_999), Ok(RomanNumeral(999_999_u64)));
+        assert_eq!(RomanNumeral::new(MAX), Ok(RomanNumeral(4_999_999_999_u64)));
+        assert_eq!(RomanNumeral(42).as_u32(), 42_u32);
+        assert_eq!(RomanNumeral(u64::from(u32::MAX)).as_u32(), u32::MAX);
+        assert_eq!(RomanNumeral(MAX).as_u32(), u32::MAX);
+        assert_eq!(RomanNumeral(MAX).as_u64(), MAX);
+        assert!(matches!(
+            RomanNumeral::new(5_000_000_000),
+            Err(OutOfRangeError)
+        ));
+        assert!(matches!(RomanNumeral::new(u64::MAX), Err(OutOfRangeError)));
     }
 
     #[test]
     fn test_try_from_one() {
-        assert_eq!(RomanNumeral::try_from(1_u8), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_u16), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_u32), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_u64), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_u128), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_usize), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_i8), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_i16), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_i32), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_i64), Ok(RomanNumeral(1_u32)));
-        assert_eq!(RomanNumeral::try_from(1_i128), Ok(RomanNumeral(1_u32)));
+        assert_eq!(RomanNumeral::try_from(1_u8), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_u16), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_u32), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_u64), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_u128), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_usize), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_i8), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_i16), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_i32), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_i64), Ok(RomanNumeral(1_u64)));
+        assert_eq!(RomanNumeral::try_from(1_i128), Ok(RomanNumeral(1_u64)));
     }
 
     #[test]
@@ -76,6 +138,1835 @@ mod test {
         assert_eq!(RomanNumeral::new(1984).unwrap().to_string(), "MCMLXXXIV");
     }
 
+    #[test]
+    fn test_roman_numeral_to_lowercase() {
+        assert_eq!(RomanNumeral::new(0).unwrap().to_lowercase(), "N");
+        assert_eq!(RomanNumeral::new(4).unwrap().to_lowercase(), "iu");
+        assert_eq!(RomanNumeral::new(1984).unwrap().to_lowercase(), "mcmlxxxiu");
+        assert_eq!(RomanNumeral::new(4000).unwrap().to_lowercase(), "i̅u̅");
+        assert_eq!(
+            RomanNumeral::new(4_999_999).unwrap().to_uppercase(),
+            "M̅M̅M̅M̅C̅M̅X̅C̅I̅X̅CMXCIX"
+        );
+        assert_eq!(
+            RomanNumeral::new(MAX).unwrap().to_uppercase(),
+            "M̿M̿M̿M̿C̿M̿X̿C̿V̿M̅M̅M̅M̅C̅M̅X̅C̅I̅X̅CMXCIX"
+        );
+    }
+
+    #[test]
+    fn test_roman_numeral_parse() {
+        assert_eq!(RomanNumeral::parse("N"), Ok(RomanNumeral(0)));
+        assert_eq!(RomanNumeral::parse("XLII"), Ok(RomanNumeral(42)));
+        assert_eq!("dcxui".parse::<RomanNumeral>(), Ok(RomanNumeral(616)));
+        assert_eq!(RomanNumeral::parse("I̅V̅"), Ok(RomanNumeral(4000)));
+        assert_eq!(
+            RomanNumeral::parse("C̅M̅X̅C̅I̅X̅CMXCIX"),
+            Ok(RomanNumeral(999_999))
+        );
+
+        let err = RomanNumeral::parse("").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Empty);
+        let err = RomanNumeral::parse("XIZ").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::InvalidCharacter, 2)
+        );
+        let err = RomanNumeral::parse("IIII").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::NonCanonical, 1)
+        );
+        let err = RomanNumeral::parse("XiV").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::NonCanonical, 1)
+        );
+        let err = RomanNumeral::parse("IC").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+        let err = RomanNumeral::parse("MMMM").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+        let err = RomanNumeral::parse("n").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidCharacter);
+        assert_eq!(RomanNumeral::parse("M̅M̅M̅M̅"), Ok(RomanNumeral(4_000_000)));
+        assert_eq!(RomanNumeral::parse("I̿V̿"), Ok(RomanNumeral(4_000_000)));
+        let err = RomanNumeral::parse("M̅M̅M̅M̅M̅").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+        let err = RomanNumeral::parse("M̿M̿M̿M̿M̿").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn test_roman_numeral_large() {
+        let billions = RomanNumeral::new(1_234_567_890).unwrap();
+        assert_eq!(billions.to_uppercase(), "M̿C̿C̿X̿X̿X̿M̅M̅M̅M̅D̅L̅X̅V̅MMDCCCXC");
+        assert_eq!(RomanNumeral::parse(&billions.to_uppercase()), Ok(billions));
+        let double = NumeralStyle::new().large(LargeNumerals::DoubleVinculum);
+        assert_eq!(
+            billions.format_with(&double),
+            Ok(String::from("M̿C̿C̿X̿X̿X̿I̿V̿D̅L̅X̅V̅MMDCCCXC"))
+        );
+        assert_eq!(RomanNumeral::parse("M̿C̿C̿X̿X̿X̿I̿V̿D̅L̅X̅V̅MMDCCCXC"), Ok(billions));
+        let nine = RomanNumeral(9_000_000);
+        assert_eq!(nine.to_uppercase(), "V̿M̅M̅M̅M̅");
+        assert_eq!(nine.format_with(&double), Ok(String::from("I̿X̿")));
+        assert_eq!(RomanNumeral::parse("I̿X̿"), Ok(nine));
+        let err = RomanNumeral::parse("M̅V̿").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+
+        let framed = NumeralStyle::new().large(LargeNumerals::Framed);
+        let formats = [
+            (99_999, "X̅C̅I̅X̅CMXCIX"),
+            (100_000, "|I̅|"),
+            (1_000_000, "|X̅|"),
+            (1_100_000, "|X̅I̅|"),
+            (1_234_567, "|X̅I̅I̅|X̅X̅X̅I̅V̅DLXVII"),
+            (400_000_000, "|I̿V̿|"),
+            (MAX, "|X̿L̿I̿X̿C̅M̅X̅C̅I̅X̅|X̅C̅I̅X̅CMXCIX"),
+        ];
+        for (value, expected) in formats {
+            let num = RomanNumeral(value);
+            assert_eq!(num.format_with(&framed), Ok(String::from(expected)));
+            assert_eq!(RomanNumeral::parse(expected), Ok(num));
+        }
+        let lower = framed.case(Case::Lower);
+        assert_eq!(
+            RomanNumeral(1_000_000).format_with(&lower),
+            Ok("|x̅|".into())
+        );
+        assert_eq!(RomanNumeral::parse("|x̅|"), Ok(RomanNumeral(1_000_000)));
+
+        let err = RomanNumeral::parse("|X̅").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+        let err = RomanNumeral::parse("|I̅I̅I̅I̅|").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+
+        let ascii = NumeralStyle::new().large(LargeNumerals::AsciiVinculum);
+        assert_eq!(
+            RomanNumeral(5_000_000).format_with(&ascii),
+            Ok("__V".into())
+        );
+
+        let num = RomanNumeral::parse_lenient("|X|").unwrap();
+        assert_eq!(num.value, RomanNumeral(1_000_000));
+        assert_eq!(num.deviations[0].kind, DeviationKind::NonCanonical);
+        let num = RomanNumeral::parse_lenient("M\u{305}\u{305}").unwrap();
+        assert_eq!(num.value, RomanNumeral(1_000_000_000));
+        assert_eq!(num.deviations[0].kind, DeviationKind::OverbarSubstitute);
+        assert!(RomanNumeral::parse_lenient("|X̅|").unwrap().is_canonical());
+        assert!(RomanNumeral::parse_lenient("I̿V̿").unwrap().is_canonical());
+        assert!(RomanNumeral::parse_lenient("M̅M̅M̅M̅").unwrap().is_canonical());
+    }
+
+    #[test]
+    fn test_roman_numeral_number_forms() {
+        let forms = NumeralStyle::new().letters(Letters::NumberForms);
+        let lower = forms.case(Case::Lower);
+        let formats = [
+            (1, "Ⅰ", "ⅰ"),
+            (4, "Ⅳ", "ⅳ"),
+            (11, "Ⅺ", "ⅺ"),
+            (12, "Ⅻ", "ⅻ"),
+            (13, "ⅩⅢ", "ⅹⅲ"),
+            (20, "ⅩⅩ", "ⅹⅹ"),
+            (112, "ⅭⅫ", "ⅽⅻ"),
+            (1984, "ⅯⅭⅯⅬⅩⅩⅩⅣ", "ⅿⅽⅿⅼⅹⅹⅹⅳ"),
+            (2012, "ⅯⅯⅫ", "ⅿⅿⅻ"),
+            (4000, "Ⅿↁ", "ⅿↁ"),
+            (16_000, "ↂↁⅯ", "ↂↁⅿ"),
+            (99_000, "ↂↈⅯↂ", "ↂↈⅿↂ"),
+            (500_000, "Ⅾ̅", "ⅾ̅"),
+        ];
+        for (value, upper, lowercase) in formats {
+            let num = RomanNumeral(value);
+            assert_eq!(num.format_with(&forms), Ok(String::from(upper)));
+            assert_eq!(num.format_with(&lower), Ok(String::from(lowercase)));
+            assert_eq!(RomanNumeral::parse(upper), Ok(num));
+            assert_eq!(RomanNumeral::parse(lowercase), Ok(num));
+            assert!(RomanNumeral::parse_lenient(upper).unwrap().is_canonical());
+        }
+        let clock = forms.additive(AdditiveForms::CLOCK);
+        assert_eq!(RomanNumeral(4).format_with(&clock), Ok("ⅠⅠⅠⅠ".into()));
+        let apostrophus = forms.large(LargeNumerals::Apostrophus(Apostrophus::Unicode));
+        assert_eq!(
+            RomanNumeral(1625).format_with(&apostrophus),
+            Ok("ⅭⅠↃⅠↃⅭⅩⅩⅤ".into())
+        );
+
+        let err = RomanNumeral::parse("ⅩⅠⅠ").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+        let err = RomanNumeral::parse("ⅯXII").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+        let num = RomanNumeral::parse_lenient("ⅩⅠⅠ").unwrap();
+        assert_eq!(num.value, RomanNumeral(12));
+        assert_eq!(num.deviations[0].kind, DeviationKind::NonCanonical);
+    }
+
+    #[test]
+    fn test_roman_quantity() {
+        let two = RomanNumeral(2);
+        let formats = [
+            (RomanQuantity::from_twelfths(two, 6), "IIS"),
+            (RomanQuantity::from_twelfths(RomanNumeral(0), 8), "S··"),
+            (RomanQuantity::from_twelfths(RomanNumeral(0), 0), "N"),
+            (RomanQuantity::from_twelfths(two, 0), "II"),
+            (RomanQuantity::new(RomanNumeral(0), 1), "𐆕"),
+            (
+                RomanQuantity::new(RomanNumeral(3), 144 + 72 + 36 + 6 + 2),
+                "III·ΣↃ℈𐆕𐆕",
+            ),
+            (RomanQuantity::new(RomanNumeral(0), 48), "Ↄ𐆔"),
+            (RomanQuantity::new(RomanNumeral(0), 1727), "S·····ΣↃƧ℈𐆕𐆕𐆕𐆕𐆕"),
+        ];
+        for (quantity, expected) in formats {
+            let quantity = quantity.unwrap();
+            assert_eq!(quantity.to_string(), expected);
+            assert_eq!(RomanQuantity::parse(expected), Ok(quantity));
+        }
+        assert_eq!(RomanQuantity::from_twelfths(two, 12), Err(OutOfRangeError));
+        assert_eq!(RomanQuantity::new(two, 1728), Err(OutOfRangeError));
+
+        let lower = RomanQuantity::from_twelfths(RomanNumeral(4), 7).unwrap();
+        let mut out = String::new();
+        lower
+            .write_with(&NumeralStyle::LOWERCASE, &mut out)
+            .unwrap();
+        assert_eq!(out, "ius·");
+
+        assert_eq!(RomanQuantity::parse("XS--").unwrap().unciae(), 8);
+        assert_eq!(RomanQuantity::parse("X S··").unwrap().unciae(), 8);
+        assert_eq!(RomanQuantity::parse("𐆐𐆑").unwrap().unciae(), 3);
+        let err = RomanQuantity::parse("·S").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::NonCanonical, 2)
+        );
+        let err = RomanQuantity::parse("SS").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+        assert_eq!(RomanQuantity::parse("·····").unwrap().unciae(), 5);
+        let err = RomanQuantity::parse("······").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::NonCanonical, 10)
+        );
+        let err = RomanQuantity::parse("S······").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::NonCanonical, 11)
+        );
+        let err = RomanQuantity::parse("𐆐····").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::NonCanonical, 10)
+        );
+        let err = RomanQuantity::parse("IIS·x").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::InvalidCharacter, 5)
+        );
+        let err = RomanQuantity::parse("IIIIS").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonCanonical);
+
+        let nearest = Rounding::Nearest;
+        let uncia = FractionUnit::Uncia;
+        let third = RomanQuantity::from_ratio(1, 3, uncia, nearest).unwrap();
+        assert_eq!(third.to_string(), "····");
+        let half = RomanQuantity::from_ratio(1, 24, uncia, nearest).unwrap();
+        assert_eq!(half.to_string(), "·");
+        let down = RomanQuantity::from_ratio(1, 24, uncia, Rounding::Down).unwrap();
+        assert_eq!(down.to_string(), "N");
+        let up = RomanQuantity::from_ratio(1, 1000, uncia, Rounding::Up).unwrap();
+        assert_eq!(up.to_string(), "·");
+        let carry = RomanQuantity::from_ratio(599, 300, uncia, nearest).unwrap();
+        assert_eq!(carry.to_string(), "II");
+        let fine = RomanQuantity::from_ratio(1, 7, FractionUnit::Siliqua, nearest).unwrap();
+        assert_eq!(fine.fraction(), 247);
+        assert_eq!(
+            RomanQuantity::from_ratio(1, 0, uncia, nearest),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            RomanQuantity::from_ratio(u64::MAX, 1, uncia, nearest),
+            Err(OutOfRangeError)
+        );
+
+        let float = RomanQuantity::from_f64(2.75, uncia, nearest).unwrap();
+        assert_eq!(float.to_string(), "IIS···");
+        assert_eq!(float.to_f64(), 2.75);
+        let scripulum = FractionUnit::Scripulum;
+        let pi = RomanQuantity::from_f64(core::f64::consts::PI, scripulum, nearest).unwrap();
+        assert_eq!(pi.to_string(), "III·ΣƧ℈");
+        assert!(RomanQuantity::from_f64(-1.0, uncia, nearest).is_err());
+        assert!(RomanQuantity::from_f64(f64::NAN, uncia, nearest).is_err());
+        assert!(RomanQuantity::from_f64(5e9, uncia, nearest).is_err());
+    }
+
+    #[test]
+    fn test_roman_numeral_arithmetic() {
+        let (two, three, forty) = (RomanNumeral(2), RomanNumeral(3), RomanNumeral(40));
+        assert_eq!(forty + two, Ok(RomanNumeral(42)));
+        assert_eq!(forty - two, Ok(RomanNumeral(38)));
+        assert_eq!(forty * three, Ok(RomanNumeral(120)));
+        assert_eq!(forty / three, Ok(RomanNumeral(13)));
+        assert_eq!(forty % three, Ok(RomanNumeral(1)));
+        assert_eq!(two - three, Err(OutOfRangeError));
+        assert_eq!(RomanNumeral(MAX) + two, Err(OutOfRangeError));
+        assert_eq!(forty / RomanNumeral(0), Err(OutOfRangeError));
+        assert_eq!(forty % RomanNumeral(0), Err(OutOfRangeError));
+
+        assert_eq!(forty.checked_add(two), Some(RomanNumeral(42)));
+        assert_eq!(two.checked_sub(three), None);
+        assert_eq!(RomanNumeral(MAX).checked_mul(two), None);
+        assert_eq!(forty.checked_div(RomanNumeral(0)), None);
+        assert_eq!(RomanNumeral(MAX).saturating_add(two), RomanNumeral(MAX));
+        assert_eq!(two.saturating_sub(three), RomanNumeral(0));
+        assert_eq!(
+            RomanNumeral(MAX).saturating_mul(RomanNumeral(u64::MAX)),
+            RomanNumeral(MAX)
+        );
+
+        let pages = [two, three, forty];
+        assert_eq!(
+            pages.iter().sum::<Result<RomanNumeral>>(),
+            Ok(RomanNumeral(45))
+        );
+        assert_eq!(
+            pages.into_iter().product::<Result<RomanNumeral>>(),
+            Ok(RomanNumeral(240))
+        );
+        let empty: [RomanNumeral; 0] = [];
+        assert_eq!(
+            empty.iter().sum::<Result<RomanNumeral>>(),
+            Ok(RomanNumeral(0))
+        );
+        assert_eq!(
+            empty.iter().product::<Result<RomanNumeral>>(),
+            Ok(RomanNumeral(1))
+        );
+        let huge = [RomanNumeral(MAX), RomanNumeral(MAX)];
+        assert_eq!(
+            huge.iter().sum::<Result<RomanNumeral>>(),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            huge.iter().product::<Result<RomanNumeral>>(),
+            Err(OutOfRangeError)
+        );
+
+        let (i, x) = (RomanNumeral(1), RomanNumeral(10));
+        let roman: Vec<String> = RomanNumeral::range(i..=x)
+            .map(|num| num.to_uppercase())
+            .collect();
+        assert_eq!(
+            roman,
+            ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X"]
+        );
+        assert_eq!(RomanNumeral::range(i..x).count(), 9);
+        assert_eq!(RomanNumeral::range(i..x).next_back(), Some(RomanNumeral(9)));
+        assert_eq!(RomanNumeral::range(..three).count(), 3);
+        assert_eq!(RomanNumeral::range(x..i).count(), 0);
+        assert_eq!(RomanNumeral::range(..RomanNumeral(0)).count(), 0);
+        let last = RomanNumeral(MAX - 1);
+        assert_eq!(RomanNumeral::range(last..).count(), 2);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_roman_numeral_num_traits() {
+        use num_traits::{Bounded, FromPrimitive, NumCast, ToPrimitive};
+
+        assert_eq!(RomanNumeral::max_value(), RomanNumeral(MAX));
+        assert_eq!(RomanNumeral::min_value(), RomanNumeral(MIN));
+        assert_eq!(RomanNumeral(42).to_u32(), Some(42));
+        assert_eq!(RomanNumeral(MAX).to_u32(), None);
+        assert_eq!(RomanNumeral::from_i32(42), Some(RomanNumeral(42)));
+        assert_eq!(RomanNumeral::from_i32(-1), None);
+        assert_eq!(RomanNumeral::from_f64(42.9), Some(RomanNumeral(42)));
+        assert_eq!(<RomanNumeral as NumCast>::from(7_u8), Some(RomanNumeral(7)));
+        assert_eq!(<RomanNumeral as NumCast>::from(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_roman_numeral_evaluate() {
+        let evaluate = |expression: &str| RomanNumeral::evaluate(expression).map(|num| num.0);
+        assert_eq!(evaluate("XII + IV * II"), Ok(20));
+        assert_eq!(evaluate("(XII + IV) * II"), Ok(32));
+        assert_eq!(evaluate("(XII+IV)/(II×II)"), Ok(4));
+        assert_eq!(evaluate("C - L - X"), Ok(40));
+        assert_eq!(evaluate("C ÷ III"), Ok(33));
+        assert_eq!(evaluate("C % III"), Ok(1));
+        assert_eq!(evaluate("  ((mcmlxxxiu))  "), Ok(1984));
+        assert_eq!(evaluate("N + I̅V̅"), Ok(4000));
+        assert_eq!(
+            evaluate("M̿M̿M̿M̿ + M̿"),
+            Err(ExpressionError::new(ExpressionErrorKind::OutOfRange, 13))
+        );
+
+        let error = |expression: &str| {
+            let err = RomanNumeral::evaluate(expression).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ExpressionErrorKind::Empty, 0));
+        assert_eq!(error("   "), (ExpressionErrorKind::Empty, 0));
+        assert_eq!(error("X + ()"), (ExpressionErrorKind::Empty, 5));
+        assert_eq!(error("I - II"), (ExpressionErrorKind::OutOfRange, 2));
+        assert_eq!(error("X / N"), (ExpressionErrorKind::DivisionByZero, 2));
+        assert_eq!(
+            error("X % (I - I)"),
+            (ExpressionErrorKind::DivisionByZero, 2)
+        );
+        assert_eq!(error("X +"), (ExpressionErrorKind::UnexpectedEnd, 3));
+        assert_eq!(error("X + * I"), (ExpressionErrorKind::UnexpectedToken, 4));
+        assert_eq!(error("X I"), (ExpressionErrorKind::UnexpectedToken, 2));
+        assert_eq!(
+            error("(X + I"),
+            (ExpressionErrorKind::UnbalancedParenthesis, 0)
+        );
+        assert_eq!(
+            error("X + I)"),
+            (ExpressionErrorKind::UnbalancedParenthesis, 5)
+        );
+        let nested = |depth: usize| format!("{}I{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(evaluate(&nested(256)), Ok(1));
+        assert_eq!(error(&nested(257)), (ExpressionErrorKind::TooDeep, 256));
+        assert_eq!(error(&nested(200_000)), (ExpressionErrorKind::TooDeep, 256));
+        assert_eq!(
+            error("X + IIII"),
+            (
+                ExpressionErrorKind::Numeral(ParseErrorKind::NonCanonical),
+                5
+            )
+        );
+        assert_eq!(
+            error("X + XIZ"),
+            (
+                ExpressionErrorKind::Numeral(ParseErrorKind::InvalidCharacter),
+                6
+            )
+        );
+    }
+
+    #[test]
+    fn test_roman_numeral_manuscript() {
+        let scribe = NumeralStyle::LOWERCASE
+            .v_letter(VLetter::V)
+            .additive(AdditiveForms::ALL)
+            .manuscript(Manuscript::NONE.terminal_j(true));
+        let superscripts = scribe.manuscript(Manuscript::NONE.terminal_j(true).superscripts(true));
+        let formats = [
+            (1, "i", "i"),
+            (2, "ij", "ij"),
+            (4, "iiij", "iiij"),
+            (7, "vij", "vij"),
+            (20, "xx", "xx"),
+            (101, "cj", "cj"),
+            (200, "cc", "ijᶜ"),
+            (1001, "mj", "mj"),
+            (1200, "mcc", "mijᶜ"),
+            (2001, "mmj", "ijᵐj"),
+            (5368, "v\u{305}ccclxviij", "vᵐiijᶜlxviij"),
+            (20_000, "x\u{305}x\u{305}", "xxᵐ"),
+            (1_000_000, "m\u{305}", "mᵐ"),
+            (2_000_000, "m\u{305}m\u{305}", "ijᵐᵐ"),
+        ];
+        for (value, letters, superscript) in formats {
+            let num = RomanNumeral(value);
+            assert_eq!(num.format_with(&scribe), Ok(letters.to_string()));
+            assert_eq!(num.format_with(&superscripts), Ok(superscript.to_string()));
+            assert_eq!(RomanNumeral::parse_manuscript(superscript), Ok(num));
+        }
+
+        let all = NumeralStyle::LOWERCASE.manuscript(Manuscript::ALL);
+        assert_eq!(
+            RomanNumeral(5367).format_with(&all),
+            Ok(".ꝟᵐiijᶜlxꝟij.".to_string())
+        );
+        assert_eq!(RomanNumeral(15).format_with(&all), Ok(".xꝟ.".to_string()));
+        assert_eq!(RomanNumeral(1).format_with(&all), Ok(".i.".to_string()));
+        assert_eq!(RomanNumeral(0).format_with(&all), Ok("N".to_string()));
+        let upper = NumeralStyle::UPPERCASE.manuscript(Manuscript::ALL);
+        assert_eq!(RomanNumeral(9).format_with(&upper), Ok(".IX.".to_string()));
+        assert_eq!(
+            RomanNumeral(8).format_with(&upper),
+            Ok(".ꝞIIJ.".to_string())
+        );
+        let dots = NumeralStyle::LOWERCASE.manuscript(Manuscript::NONE.dots(true));
+        assert_eq!(RomanNumeral(20).format_with(&dots), Ok(".xx.".to_string()));
+
+        assert_eq!(
+            RomanNumeral(4000).format_with(&dots),
+            Ok(".i\u{305}u\u{305}.".to_string())
+        );
+
+        let flags = [false, true];
+        for (j, superscript, dot, stroked) in flags.into_iter().flat_map(|j| {
+            flags.into_iter().flat_map(move |superscript| {
+                flags
+                    .into_iter()
+                    .flat_map(move |dot| flags.map(move |stroked| (j, superscript, dot, stroked)))
+            })
+        }) {
+            let manuscript = Manuscript::NONE
+                .terminal_j(j)
+                .superscripts(superscript)
+                .dots(dot)
+                .stroked_v(stroked);
+            let styles = [
+                NumeralStyle::LOWERCASE,
+                NumeralStyle::UPPERCASE,
+                scribe,
+                NumeralStyle::UPPERCASE.large(LargeNumerals::DoubleVinculum),
+                NumeralStyle::LOWERCASE.zero(Zero::Nulla),
+            ];
+            for style in styles.map(|style| style.manuscript(manuscript)) {
+                for value in (0..5000).chain((5000..=MAX).step_by(9_999_991)) {
+                    let num = RomanNumeral(value);
+                    let text = num.format_with(&style).unwrap();
+                    assert_eq!(RomanNumeral::parse_manuscript(&text), Ok(num), "{text}");
+                }
+            }
+        }
+        assert_eq!(
+            RomanNumeral::parse_manuscript("xiiij"),
+            Ok(RomanNumeral(14))
+        );
+        assert_eq!(RomanNumeral::parse_manuscript("xiv"), Ok(RomanNumeral(14)));
+        assert_eq!(
+            RomanNumeral::parse_manuscript("XIIIJ"),
+            Ok(RomanNumeral(14))
+        );
+        assert_eq!(RomanNumeral::parse_manuscript("N"), Ok(RomanNumeral(0)));
+
+        let error = |s: &str| {
+            let err = RomanNumeral::parse_manuscript(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error(".."), (ParseErrorKind::Empty, 0));
+        assert_eq!(error(".x;."), (ParseErrorKind::InvalidCharacter, 2));
+        assert_eq!(error("v\u{304}"), (ParseErrorKind::InvalidCharacter, 1));
+        assert_eq!(error("iji"), (ParseErrorKind::NonCanonical, 1));
+        assert_eq!(error("xiiijx"), (ParseErrorKind::NonCanonical, 1));
+        assert_eq!(error("jᶜ"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("mᵐᵐᵐᵐᵐᵐᵐiᵐ"), (ParseErrorKind::OutOfRange, 0));
+        assert_eq!(error(&"iᵐ".repeat(40)), (ParseErrorKind::OutOfRange, 0));
+        assert_eq!(error(".xx"), (ParseErrorKind::InvalidCharacter, 0));
+    }
+
+    #[test]
+    fn test_latin_words() {
+        let cardinal = LatinWords::new();
+        let ordinal = LatinWords::new().kind(WordKind::Ordinal);
+        let distributive = LatinWords::new().kind(WordKind::Distributive);
+        let adverb = LatinWords::new().kind(WordKind::Adverb);
+        let formats = [
+            (cardinal, 1, "ūnus"),
+            (cardinal, 2, "duo"),
+            (cardinal, 3, "trēs"),
+            (cardinal, 18, "duodēvīgintī"),
+            (cardinal, 21, "vīgintī ūnus"),
+            (cardinal, 98, "nōnāgintā octō"),
+            (cardinal, 100, "centum"),
+            (cardinal, 200, "ducentī"),
+            (cardinal, 1000, "mīlle"),
+            (cardinal, 1001, "mīlle ūnus"),
+            (cardinal, 2024, "duo mīlia vīgintī quattuor"),
+            (
+                cardinal,
+                999_999,
+                "nōngenta nōnāgintā novem mīlia nōngentī nōnāgintā novem",
+            ),
+            (cardinal.gender(Gender::Feminine), 300, "trecentae"),
+            (
+                cardinal
+                    .gender(Gender::Feminine)
+                    .case(GrammaticalCase::Genitive),
+                2203,
+                "duōrum mīlium ducentārum trium",
+            ),
+            (
+                cardinal
+                    .gender(Gender::Neuter)
+                    .case(GrammaticalCase::Accusative),
+                3,
+                "tria",
+            ),
+            (cardinal.case(GrammaticalCase::Genitive), 1, "ūnīus"),
+            (ordinal, 1, "prīmus"),
+            (ordinal, 13, "tertius decimus"),
+            (ordinal, 18, "duodēvīcēsimus"),
+            (ordinal, 21, "vīcēsimus prīmus"),
+            (ordinal, 1000, "mīllēsimus"),
+            (ordinal, 2024, "bis mīllēsimus vīcēsimus quārtus"),
+            (ordinal.case(GrammaticalCase::Vocative), 1, "prīme"),
+            (
+                ordinal
+                    .gender(Gender::Feminine)
+                    .case(GrammaticalCase::Ablative),
+                13,
+                "tertiā decimā",
+            ),
+            (distributive, 1, "singulī"),
+            (distributive, 15, "quīnī dēnī"),
+            (distributive, 19, "ūndēvīcēnī"),
+            (distributive, 1000, "singula mīlia"),
+            (distributive, 3000, "terna mīlia"),
+            (
+                distributive
+                    .gender(Gender::Feminine)
+                    .case(GrammaticalCase::Accusative),
+                2,
+                "bīnās",
+            ),
+            (adverb, 1, "semel"),
+            (adverb, 3, "ter"),
+            (adverb, 14, "quaterdeciēs"),
+            (adverb, 28, "duodētrīciēs"),
+            (adverb, 1000, "mīliēs"),
+            (adverb, 2021, "bis mīliēs vīciēs semel"),
+            (adverb.case(GrammaticalCase::Dative), 2, "bis"),
+        ];
+        for (words, value, text) in formats {
+            assert_eq!(words.format(value), Ok(String::from(text)));
+            let (parsed, _) = LatinWords::parse(text).unwrap();
+            assert_eq!(parsed, value);
+        }
+        assert_eq!(cardinal.format(0), Err(OutOfRangeError));
+        assert_eq!(cardinal.format(1_000_000), Err(OutOfRangeError));
+
+        for kind in [
+            WordKind::Cardinal,
+            WordKind::Ordinal,
+            WordKind::Distributive,
+            WordKind::Adverb,
+        ] {
+            let words = LatinWords::new().kind(kind).case(GrammaticalCase::Ablative);
+            for value in (1..=120).chain((1..=LatinWords::MAX).step_by(4999)) {
+                let text = words.format(value).unwrap();
+                let (parsed, found) = LatinWords::parse(&text).unwrap();
+                assert_eq!((parsed, found.format(value).unwrap()), (value, text));
+            }
+        }
+
+        assert_eq!(LatinWords::parse("DUODEVICESIMUS"), Ok((18, ordinal)));
+        assert_eq!(
+            LatinWords::parse("  Trium   Milium "),
+            Ok((3000, cardinal.case(GrammaticalCase::Genitive)))
+        );
+        assert_eq!(
+            LatinWords::parse("bi\u{304}na"),
+            Ok((2, distributive.gender(Gender::Neuter)))
+        );
+
+        let error = |s: &str| {
+            let err = LatinWords::parse(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("   "), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("duo trēsque"), (ParseErrorKind::InvalidCharacter, 4));
+        assert_eq!(error("ūnus vīgintī"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(
+            error("vīgintī ūnus duo"),
+            (ParseErrorKind::NonCanonical, 10)
+        );
+        assert_eq!(error("prīmus bis"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(
+            error("nōngenta mīlia nōngenta mīlia"),
+            (ParseErrorKind::OutOfRange, 0)
+        );
+
+        let num = RomanNumeral::new(18).unwrap();
+        assert_eq!(
+            num.format_latin(&cardinal),
+            Ok(String::from("duodēvīgintī"))
+        );
+        assert_eq!(
+            RomanNumeral(0).format_latin(&cardinal),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            RomanNumeral(MAX).format_latin(&cardinal),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            RomanNumeral::parse_latin("ter mīliēs"),
+            Ok(RomanNumeral(3000))
+        );
+    }
+
+    #[test]
+    fn test_chronogram() {
+        let rules = ChronogramRules::new();
+        let chronogram = rules.read("ChrIstVs DVX ergo trIVMphVs").unwrap();
+        assert_eq!(chronogram.value, RomanNumeral(1632));
+        let letters: String = chronogram.letters.iter().map(|l| l.letter).collect();
+        assert_eq!(letters, "CIVDVXIVMV");
+        assert_eq!(
+            chronogram.letters[3],
+            ChronogramLetter {
+                letter: 'D',
+                value: 500,
+                position: 9,
+            }
+        );
+        // The lowercase u of DuX is not a capital, and does not count
+        assert_eq!(
+            rules.read("ChrIstVs DuX ergo trIVMphVs").unwrap().value,
+            RomanNumeral(1627)
+        );
+        assert!(rules.encodes("ChrIstVs DVX ergo trIVMphVs", 1632));
+        assert!(!rules.encodes("ChrIstVs DuX ergo trIVMphVs", 1632));
+
+        assert_eq!(rules.read("IESUS").unwrap().value, RomanNumeral(6));
+        assert_eq!(
+            rules.u_as_v(false).read("IESUS").unwrap().value,
+            RomanNumeral(1)
+        );
+        assert_eq!(rules.read("JESVS").unwrap().value, RomanNumeral(6));
+        assert_eq!(
+            rules.j_as_i(false).read("JESVS").unwrap().value,
+            RomanNumeral(5)
+        );
+        assert_eq!(rules.read("WIR").unwrap().value, RomanNumeral(11));
+        assert_eq!(
+            rules.w_as_double_v(false).read("WIR").unwrap().value,
+            RomanNumeral(1)
+        );
+        assert_eq!(
+            rules.capitals_only(false).read("mix Max").unwrap().value,
+            RomanNumeral(2021)
+        );
+        assert_eq!(rules.read("nothing here").unwrap().value, RomanNumeral(0));
+        assert!(rules.read("").unwrap().letters.is_empty());
+
+        let too_large = "M".repeat(5_000_000);
+        assert_eq!(rules.read(&too_large), Err(OutOfRangeError));
+    }
+
+    #[test]
+    fn test_numeral_scanner() {
+        let scanner = NumeralScanner::new();
+        let found = scanner.scan("I read Chapter XII in DC.");
+        assert_eq!(
+            found,
+            vec![NumeralMatch {
+                value: RomanNumeral(12),
+                span: 15..18,
+            }]
+        );
+        let values = |scanner: NumeralScanner, text: &str| -> Vec<u64> {
+            scanner
+                .scan(text)
+                .iter()
+                .map(|found| found.value.as_u64())
+                .collect()
+        };
+        // Not canonical, mixed case, or joined to other letters
+        assert_eq!(
+            values(scanner, "CIVIL Mix VV IIII I'm XIVth MMXXIV"),
+            vec![2024]
+        );
+        assert_eq!(values(scanner, "mix civil xiv DIV"), Vec::<u64>::new());
+        assert_eq!(values(scanner.lowercase(true), "pp. xiv-xvi"), vec![14, 16]);
+        assert_eq!(
+            values(scanner.lowercase(true), "a mix of i"),
+            Vec::<u64>::new()
+        );
+        assert_eq!(
+            values(
+                scanner.lowercase(true),
+                "12 cm and 5 ml, 3 mm, 2 mi, 50 cc or 4 dl"
+            ),
+            Vec::<u64>::new()
+        );
+        assert_eq!(values(scanner, "Chapter I, Act IV"), vec![1, 4]);
+        assert_eq!(values(scanner, "VOL. DC"), vec![600]);
+
+        let headings = scanner.context(ScanContext::ANY.heading(true));
+        assert_eq!(values(headings, "Part II of X, Book IX"), vec![2, 9]);
+        let periods = scanner.context(ScanContext::ANY.period(true));
+        assert_eq!(values(periods, "I. Preface II. Kings V"), vec![1, 2]);
+        let both = scanner.context(ScanContext::ANY.heading(true).period(true));
+        assert_eq!(values(both, "Canto V, X. and L"), vec![5, 10]);
+
+        let keywords = scanner.keywords(&["psalmus"]).excluded(&[]);
+        assert_eq!(values(keywords, "Psalmus I"), vec![1]);
+        assert_eq!(values(keywords, "I MIX"), vec![1, 1009]);
+
+        assert_eq!(
+            scanner.to_arabic("Book III, Chapter I: XIV. I saw MMXXIV"),
+            "Book 3, Chapter 1: 14. I saw 2024"
+        );
+        assert_eq!(
+            scanner.scan("Anno M\u{305}CMXCIX"),
+            vec![NumeralMatch {
+                value: RomanNumeral(1_000_999),
+                span: 5..14,
+            }]
+        );
+        assert_eq!(scanner.to_arabic("I\u{305}V\u{305}DC"), "4600");
+        assert_eq!(
+            scanner.to_arabic("|X\u{305}I\u{305}| and i\u{305}v\u{305}"),
+            "1100000 and i\u{305}v\u{305}"
+        );
+        assert_eq!(
+            values(scanner, "V\u{305}V\u{305} I\u{305}I"),
+            Vec::<u64>::new()
+        );
+        let kal = Kalendarium::new("3300", "1", "1").unwrap();
+        assert!(scanner.to_arabic(&kal.to_str()).contains(" 4053 a.u.c."));
+        assert_eq!(
+            scanner.to_roman("Chapter 12, pp. 4-9", &NumeralStyle::UPPERCASE),
+            "Chapter XII, pp. IV-IX"
+        );
+        assert_eq!(
+            scanner.to_roman(
+                "3.14 or 1,000 or 007 or 0 or 5000000000 or 4th but 1999",
+                &NumeralStyle::LOWERCASE.zero(Zero::Error)
+            ),
+            "3.14 or 1,000 or 007 or 0 or 5000000000 or 4th but mcmxcix"
+        );
+        assert_eq!(
+            periods.to_roman("1. Preface 2. Kings 3", &NumeralStyle::UPPERCASE),
+            "I. Preface II. Kings 3"
+        );
+        let round = "Chapter XIV. 1999 in MMXXIV";
+        assert_eq!(
+            scanner.to_roman(&scanner.to_arabic(round), &NumeralStyle::UPPERCASE),
+            "Chapter XIV. MCMXCIX in MMXXIV"
+        );
+    }
+
+    #[test]
+    fn test_natural_sort() {
+        use core::cmp::Ordering;
+
+        let scanner = NumeralScanner::REGNAL;
+        let mut names = vec![
+            "Louis XVIII",
+            "Henry X",
+            "Henry IX",
+            "Charles II",
+            "Henry",
+            "Louis XIV",
+            "Henry VIII",
+            "Charles I",
+            "Pius 12",
+            "Pius XI",
+            "Henry VIII of England",
+        ];
+        names.sort_by(|a, b| scanner.compare(a, b));
+        assert_eq!(
+            names,
+            [
+                "Charles I",
+                "Charles II",
+                "Henry",
+                "Henry VIII",
+                "Henry VIII of England",
+                "Henry IX",
+                "Henry X",
+                "Louis XIV",
+                "Louis XVIII",
+                "Pius XI",
+                "Pius 12",
+            ]
+        );
+        names.sort_by_cached_key(|name| scanner.sort_key(name));
+        assert_eq!(names[0], "Charles I");
+
+        assert!("Louis XIX" < "Louis XVIII");
+        assert_eq!(
+            scanner.compare("Louis XIX", "Louis XVIII"),
+            Ordering::Greater
+        );
+        assert_eq!(scanner.compare("Louis XIV", "Louis 14"), Ordering::Greater);
+        assert_eq!(scanner.compare("Louis XIV", "Louis XIV"), Ordering::Equal);
+        assert_eq!(scanner.compare("XII Caesars", "Caesars"), Ordering::Less);
+        // CIVIL is not canonical, so it is compared as text
+        assert_eq!(scanner.compare("CIVIL", "CIV"), Ordering::Greater);
+        assert_eq!(scanner.compare("CIV", "CV"), Ordering::Less);
+
+        // The default scanner does not read I, and sorts it as text
+        let default = NumeralScanner::new();
+        assert_eq!(
+            default.compare("Charles I", "Charles II"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            scanner.lowercase(true).compare("page ix", "page viii"),
+            Ordering::Greater
+        );
+        assert_eq!(scanner.compare("page ix", "page viii"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_counter_style() {
+        let formats = [
+            (CounterStyle::Decimal, 0, "0"),
+            (CounterStyle::Decimal, 42, "42"),
+            (CounterStyle::DecimalLeadingZero, 5, "05"),
+            (CounterStyle::DecimalLeadingZero, 123, "123"),
+            (CounterStyle::UpperRoman, 1, "I"),
+            (CounterStyle::UpperRoman, 3999, "MMMCMXCIX"),
+            (CounterStyle::UpperRoman, 4000, "4000"),
+            (CounterStyle::UpperRoman, 0, "0"),
+            (CounterStyle::LowerRoman, 14, "xiv"),
+            (CounterStyle::LowerRoman, 1984, "mcmlxxxiv"),
+            (CounterStyle::LowerRoman, 5000, "5000"),
+            (CounterStyle::UpperAlpha, 1, "A"),
+            (CounterStyle::UpperAlpha, 26, "Z"),
+            (CounterStyle::UpperAlpha, 27, "AA"),
+            (CounterStyle::UpperAlpha, 702, "ZZ"),
+            (CounterStyle::UpperAlpha, 703, "AAA"),
+            (CounterStyle::LowerAlpha, 0, "0"),
+            (CounterStyle::LowerAlpha, 28, "ab"),
+            (CounterStyle::LowerGreek, 24, "ω"),
+            (CounterStyle::LowerGreek, 25, "αα"),
+        ];
+        for (style, value, label) in formats {
+            assert_eq!(style.format(value), label);
+            assert_eq!(style.parse(label), Ok(value));
+        }
+        for style in CounterStyle::ALL {
+            assert_eq!(style.to_string().parse(), Ok(style));
+            for value in (0..=5000).chain([u64::MAX - 1, u64::MAX]) {
+                assert_eq!(style.parse(&style.format(value)), Ok(value));
+            }
+        }
+        assert_eq!("lower-latin".parse(), Ok(CounterStyle::LowerAlpha));
+        assert_eq!("roman".parse::<CounterStyle>(), Err(ParseFormatError));
+
+        let error = |style: CounterStyle, s: &str| {
+            let err = style.parse(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(CounterStyle::Decimal, ""), (ParseErrorKind::Empty, 0));
+        assert_eq!(
+            error(CounterStyle::Decimal, "1x"),
+            (ParseErrorKind::InvalidCharacter, 1)
+        );
+        assert_eq!(
+            error(CounterStyle::Decimal, "007"),
+            (ParseErrorKind::NonCanonical, 0)
+        );
+        assert_eq!(
+            error(CounterStyle::Decimal, "99999999999999999999"),
+            (ParseErrorKind::OutOfRange, 0)
+        );
+        assert_eq!(
+            error(CounterStyle::DecimalLeadingZero, "5"),
+            (ParseErrorKind::NonCanonical, 0)
+        );
+        assert_eq!(
+            error(CounterStyle::LowerRoman, "xiu"),
+            (ParseErrorKind::InvalidCharacter, 2)
+        );
+        assert_eq!(
+            error(CounterStyle::LowerRoman, "XIV"),
+            (ParseErrorKind::InvalidCharacter, 0)
+        );
+        assert_eq!(
+            error(CounterStyle::LowerRoman, "xiiii"),
+            (ParseErrorKind::NonCanonical, 2)
+        );
+        assert_eq!(
+            error(CounterStyle::UpperRoman, "MMMM"),
+            (ParseErrorKind::NonCanonical, 0)
+        );
+        assert_eq!(
+            error(CounterStyle::UpperRoman, "12"),
+            (ParseErrorKind::NonCanonical, 0)
+        );
+        assert_eq!(
+            error(CounterStyle::LowerGreek, "αb"),
+            (ParseErrorKind::InvalidCharacter, 2)
+        );
+
+        let outline = Outline::DEFAULT;
+        let labels: Vec<String> = (0..6).map(|depth| outline.label(depth, 4)).collect();
+        assert_eq!(labels, ["IV.", "D.", "4.", "d.", "iv.", "IV."]);
+        assert_eq!(outline.parse_label(1, "B."), Ok(2));
+        assert_eq!(outline.parse_label(4, "xii."), Ok(12));
+        assert_eq!(
+            outline.parse_label(0, "IV").unwrap_err().kind(),
+            ParseErrorKind::NonCanonical
+        );
+        let custom = Outline::new(&[CounterStyle::Decimal, CounterStyle::LowerGreek], ")");
+        assert_eq!(custom.label(1, 3), "γ)");
+        assert_eq!(custom.label(2, 3), "3)");
+        assert_eq!(custom.parse_label(1, "γ)"), Ok(3));
+        assert_eq!(Outline::new(&[], "").label(7, 3), "3");
+
+        let lower = CounterStyle::LowerRoman;
+        assert_eq!(lower.format_pages(12..=15), "pp. xii–xv");
+        assert_eq!(lower.format_pages(12..=12), "p. xii");
+        assert_eq!(lower.format_pages(3998..=4001), "pp. mmmcmxcviii–4001");
+        assert_eq!(lower.parse_pages("pp. xii–xv"), Ok(12..=15));
+        assert_eq!(lower.parse_pages("xii-xv"), Ok(12..=15));
+        assert_eq!(lower.parse_pages("p. xii"), Ok(12..=12));
+        assert_eq!(lower.parse_pages("pp. mmmcmxcviii–4001"), Ok(3998..=4001));
+        let error = |s: &str| {
+            let err = lower.parse_pages(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error("pp. xv–xii"), (ParseErrorKind::NonCanonical, 9));
+        assert_eq!(error("pp. xii–xu"), (ParseErrorKind::InvalidCharacter, 11));
+        assert_eq!(error("pp. xii–"), (ParseErrorKind::Empty, 10));
+        assert_eq!(error("pp. "), (ParseErrorKind::Empty, 4));
+    }
+
+    #[test]
+    fn test_markup() {
+        let upper = NumeralStyle::UPPERCASE;
+        let formats = [
+            (
+                4600,
+                "<span class=\"vinculum\" style=\"text-decoration: overline\">IV</span>DC",
+                "\\(\\overline{\\mbox{IV}}\\)DC",
+                "#overline[IV]DC",
+            ),
+            (42, "XLII", "XLII", "XLII"),
+            (
+                1_000_000_000,
+                "<span class=\"vinculum\" style=\"border-top: 1px solid; padding-top: 1px\">\
+                 <span class=\"vinculum\" style=\"text-decoration: overline\">M</span></span>",
+                "\\(\\overline{\\overline{\\mbox{M}}}\\)",
+                "#overline[#overline[M]]",
+            ),
+            (
+                5_005_000,
+                "<span class=\"vinculum\" style=\"border-top: 1px solid; padding-top: 1px\">\
+                 <span class=\"vinculum\" style=\"text-decoration: overline\">V</span></span>\
+                 <span class=\"vinculum\" style=\"text-decoration: overline\">V</span>",
+                "\\(\\overline{\\overline{\\mbox{V}}}\\)\\(\\overline{\\mbox{V}}\\)",
+                "#overline[#overline[V]]#overline[V]",
+            ),
+        ];
+        for (value, html, latex, typst) in formats {
+            let num = RomanNumeral::new(value).unwrap();
+            assert_eq!(
+                num.format_markup(&upper, Markup::Html),
+                Ok(String::from(html))
+            );
+            assert_eq!(
+                num.format_markup(&upper, Markup::Latex),
+                Ok(String::from(latex))
+            );
+            assert_eq!(
+                num.format_markup(&upper, Markup::Typst),
+                Ok(String::from(typst))
+            );
+            // The same markup from the text of the numeral
+            let text = num.to_uppercase();
+            assert_eq!(Markup::Html.render(&text), html);
+            assert_eq!(Markup::Latex.render(&text), latex);
+            assert_eq!(Markup::Typst.render(&text), typst);
+        }
+
+        let framed = upper.large(LargeNumerals::Framed);
+        let num = RomanNumeral::new(1_234_567).unwrap();
+        assert_eq!(
+            num.format_markup(&framed, Markup::Typst),
+            Ok(String::from("|#overline[XII]|#overline[XXXIV]DLXVII"))
+        );
+        assert_eq!(
+            num.format_markup(&framed, Markup::Latex),
+            Ok(String::from(
+                "\\(\\left|\\overline{\\mbox{XII}}\\right|\\)\\(\\overline{\\mbox{XXXIV}}\\)DLXVII"
+            ))
+        );
+        assert_eq!(
+            num.format_markup(&framed, Markup::Html),
+            Ok(String::from(
+                "<span class=\"framed\" style=\"border-left: 1px solid; border-right: 1px solid\">\
+                 <span class=\"vinculum\" style=\"text-decoration: overline\">XII</span></span>\
+                 <span class=\"vinculum\" style=\"text-decoration: overline\">XXXIV</span>DLXVII"
+            ))
+        );
+        let text = num.format_with(&framed).unwrap();
+        assert_eq!(
+            Markup::Typst.render(&text),
+            "|#overline[XII]|#overline[XXXIV]DLXVII"
+        );
+
+        let precomposed = upper.overbar(Overbar::Precomposed).case(Case::Lower);
+        assert_eq!(
+            RomanNumeral::new(4000)
+                .unwrap()
+                .format_markup(&precomposed, Markup::Typst),
+            Ok(String::from("#overline[iu]"))
+        );
+        assert_eq!(
+            RomanNumeral(0).format_markup(&upper.zero(Zero::Error), Markup::Html),
+            Err(OutOfRangeError)
+        );
+
+        assert_eq!(
+            Markup::Typst.render("I\u{305}V\u{305}(x) V\u{305}[y] X\u{305}. I\u{305}x"),
+            "#overline[IV];(x) #overline[V];\\[y\\] #overline[X];. #overline[I]x"
+        );
+        assert_eq!(Markup::Typst.render("|X\u{305}|(x)"), "|#overline[X]|(x)");
+        assert_eq!(Markup::Latex.render("a|b"), "a\\textbar{}b");
+        assert_eq!(
+            Markup::Html.render("a < b & I\u{305}V\u{305}"),
+            "a &lt; b &amp; <span class=\"vinculum\" style=\"text-decoration: overline\">IV</span>"
+        );
+        assert_eq!(
+            Markup::Latex.render("50% of #1_{x}"),
+            "50\\% of \\#1\\_\\{x\\}"
+        );
+        assert_eq!(Markup::Typst.render("[*a|b|*]"), "\\[\\*a|b|\\*\\]");
+        assert_eq!(Markup::Typst.render("|x| \u{305}"), "|x| \u{305}");
+
+        let kal = Kalendarium::new("3300", "1", "1").unwrap();
+        let typst = kal.to_markup(Markup::Typst);
+        assert!(typst.contains("#overline[IV]LIII a.u.c."));
+        assert!(!typst.contains('\u{305}'));
+    }
+
+    #[test]
+    fn test_inscription() {
+        let inscription = Inscription::new();
+        assert_eq!(
+            inscription.render("I"),
+            Ok(String::from(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"16\" height=\"80\" \
+                 viewBox=\"-4 -12 8 40\"><title>I</title><path d=\"M0 0L0 20\" fill=\"none\" \
+                 stroke=\"currentColor\" stroke-width=\"2\" stroke-linecap=\"round\" \
+                 stroke-linejoin=\"round\"/></svg>"
+            ))
+        );
+        let drawing = |text: &str| {
+            let svg = inscription.render(text).unwrap();
+            svg[svg.find("</title>").unwrap()..].to_string()
+        };
+        assert_eq!(drawing("ūnus"), drawing("VNVS"));
+        assert_eq!(drawing("Iulius"), drawing("IVLIVS"));
+        assert_eq!(drawing("w"), drawing("VV"));
+
+        let svg = RomanNumeral::new(4000).unwrap().to_svg(&inscription);
+        assert!(svg.contains("d=\"M0 0L0 20M4 0L11 20L18 0M-1 -4L19 -4\""));
+        let svg = inscription.render("I\u{33f}").unwrap();
+        assert!(svg.contains("d=\"M0 0L0 20M-1 -4L1 -4M-1 -8L1 -8\""));
+        let svg = inscription.render("|X\u{305}|").unwrap();
+        assert!(svg.contains("M3 -4L18 -4"));
+
+        assert!(
+            inscription
+                .render("I I")
+                .unwrap()
+                .contains("d=\"M0 0L0 20M12 0L12 20M6 10h0\"")
+        );
+        assert!(
+            inscription
+                .render("I.")
+                .unwrap()
+                .contains("d=\"M0 0L0 20\"")
+        );
+        assert!(
+            inscription
+                .interpuncts(false)
+                .render("I I")
+                .unwrap()
+                .contains("d=\"M0 0L0 20M12 0L12 20\"")
+        );
+
+        let ligatures = inscription.ligatures(true);
+        assert!(
+            ligatures
+                .render("NT")
+                .unwrap()
+                .contains("viewBox=\"-4 -12 26 40\"")
+        );
+        assert!(
+            inscription
+                .render("NT")
+                .unwrap()
+                .contains("viewBox=\"-4 -12 36 40\"")
+        );
+        assert!(
+            ligatures
+                .render("N T")
+                .unwrap()
+                .contains("viewBox=\"-4 -12 44 40\"")
+        );
+        assert!(
+            inscription
+                .height(20)
+                .render("I")
+                .unwrap()
+                .contains("width=\"8\" height=\"40\"")
+        );
+
+        assert_eq!(
+            inscription.render("MM3"),
+            Err(ParseNumeralError::new(ParseErrorKind::InvalidCharacter, 2))
+        );
+        assert_eq!(
+            inscription.render(" \u{305}"),
+            Err(ParseNumeralError::new(ParseErrorKind::InvalidCharacter, 1))
+        );
+
+        for month in 1..=12 {
+            for day in 1..=28 {
+                let kal = Kalendarium::new("2025", &month.to_string(), &day.to_string()).unwrap();
+                assert!(kal.to_svg(&ligatures).starts_with("<svg "));
+            }
+        }
+        let kal = Kalendarium::new("-43", "3", "15").unwrap();
+        assert!(kal.to_svg(&inscription).contains("<title>"));
+    }
+
+    #[test]
+    fn test_greek_numeral() {
+        assert_eq!(GreekNumeral::new(42), Ok(GreekNumeral(42)));
+        assert_eq!(GreekNumeral::new(100_000_000), Err(OutOfRangeError));
+        assert_eq!(GreekNumeral::try_from(-1_i32), Err(OutOfRangeError));
+        assert_eq!(GreekNumeral::try_from(1_u64), Ok(GreekNumeral(1)));
+
+        let diophantus = GreekStyle::new().myriads(Myriads::Diophantus);
+        let formats = [
+            (0, "𐆊", "𐆊", "𐆊"),
+            (6, "Ϛʹ", "ϛʹ", "Ϛʹ"),
+            (42, "ΜΒʹ", "μβʹ", "ΜΒʹ"),
+            (99, "ϞΘʹ", "ϟθʹ", "ϞΘʹ"),
+            (999, "ϠϞΘʹ", "ϡϟθʹ", "ϠϞΘʹ"),
+            (1000, "͵Αʹ", "͵αʹ", "͵Αʹ"),
+            (1984, "͵ΑϠΠΔʹ", "͵αϡπδʹ", "͵ΑϠΠΔʹ"),
+            (20_000, "ΒʹΜ", "βʹΜ", "Β.ʹ"),
+            (1_234_567, "ΡΚΓʹΜ͵ΔΦΞΖʹ", "ρκγʹΜ͵δφξζʹ", "ΡΚΓ.͵ΔΦΞΖʹ"),
+            (
+                GreekNumeral::MAX,
+                "͵ΘϠϞΘʹΜ͵ΘϠϞΘʹ",
+                "͵θϡϟθʹΜ͵θϡϟθʹ",
+                "͵ΘϠϞΘ.͵ΘϠϞΘʹ",
+            ),
+        ];
+        for (value, upper, lower, dot) in formats {
+            let num = GreekNumeral(value);
+            assert_eq!(num.to_uppercase(), upper);
+            assert_eq!(num.to_lowercase(), lower);
+            assert_eq!(num.format_with(&diophantus), dot);
+            assert_eq!(GreekNumeral::parse(upper), Ok(num));
+            assert_eq!(GreekNumeral::parse(lower), Ok(num));
+            assert_eq!(GreekNumeral::parse(dot), Ok(num));
+        }
+        assert_eq!(GreekNumeral(42).to_string(), "ΜΒʹ");
+        let bare = GreekStyle::new().keraia(false);
+        assert_eq!(GreekNumeral(42).format_with(&bare), "ΜΒ");
+        assert_eq!(GreekNumeral(10_042).format_with(&bare), "ΑʹΜΜΒ");
+
+        assert_eq!("ΜΒ".parse::<GreekNumeral>(), Ok(GreekNumeral(42)));
+        assert_eq!("ΜΒ'".parse::<GreekNumeral>(), Ok(GreekNumeral(42)));
+        assert_eq!("ΜΒ\u{374}".parse::<GreekNumeral>(), Ok(GreekNumeral(42)));
+        assert_eq!("ϙ".parse::<GreekNumeral>(), Ok(GreekNumeral(90)));
+        for value in (0..10_000).chain((10_000..=GreekNumeral::MAX).step_by(9_973)) {
+            let num = GreekNumeral(value);
+            assert_eq!(GreekNumeral::parse(&num.to_uppercase()), Ok(num));
+            assert_eq!(GreekNumeral::parse(&num.format_with(&diophantus)), Ok(num));
+        }
+
+        let error = |s: &str| {
+            let err = GreekNumeral::parse(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("ʹ"), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("ΒΜʹ"), (ParseErrorKind::NonCanonical, 2));
+        assert_eq!(error("ΜΜʹ"), (ParseErrorKind::NonCanonical, 2));
+        assert_eq!(error("Μβʹ"), (ParseErrorKind::NonCanonical, 2));
+        assert_eq!(error("ΜΖ1"), (ParseErrorKind::InvalidCharacter, 4));
+        assert_eq!(error("͵Μ"), (ParseErrorKind::NonCanonical, 2));
+        assert_eq!(error("Α͵Β"), (ParseErrorKind::NonCanonical, 4));
+        assert_eq!(error("Β͵"), (ParseErrorKind::NonCanonical, 4));
+    }
+
+    #[test]
+    fn test_attic_numeral() {
+        assert_eq!(AtticNumeral::new(0), Err(OutOfRangeError));
+        assert_eq!(AtticNumeral::new(100_000), Err(OutOfRangeError));
+        assert_eq!(AtticNumeral::try_from(-5_i64), Err(OutOfRangeError));
+        assert_eq!(AtticNumeral::try_from(42_u8), Ok(AtticNumeral(42)));
+
+        let formats = [
+            (1, "Ι", "𐅂", "Τ"),
+            (4, "ΙΙΙΙ", "𐅂𐅂𐅂𐅂", "ΤΤΤΤ"),
+            (17, "ΔΠΙΙ", "ΔΠ𐅂𐅂", "𐅉𐅈ΤΤ"),
+            (1367, "ΧΗΗΗ𐅄ΔΠΙΙ", "ΧΗΗΗ𐅄ΔΠ𐅂𐅂", "𐅍𐅋𐅋𐅋𐅊𐅉𐅈ΤΤ"),
+            (5500, "𐅆𐅅", "𐅆𐅅", "𐅎𐅌"),
+            (60_000, "𐅇Μ", "𐅇Μ", "𐅇Μ"),
+            (
+                AtticNumeral::MAX,
+                "𐅇ΜΜΜΜ𐅆ΧΧΧΧ𐅅ΗΗΗΗ𐅄ΔΔΔΔΠΙΙΙΙ",
+                "𐅇ΜΜΜΜ𐅆ΧΧΧΧ𐅅ΗΗΗΗ𐅄ΔΔΔΔΠ𐅂𐅂𐅂𐅂",
+                "𐅇ΜΜΜΜ𐅎𐅍𐅍𐅍𐅍𐅌𐅋𐅋𐅋𐅋𐅊𐅉𐅉𐅉𐅉𐅈ΤΤΤΤ",
+            ),
+        ];
+        for (value, number, drachmas, talents) in formats {
+            let num = AtticNumeral(value);
+            assert_eq!(num.to_string(), number);
+            assert_eq!(num.format_with(AtticUnit::Drachmas), drachmas);
+            assert_eq!(num.format_with(AtticUnit::Talents), talents);
+            assert_eq!(AtticNumeral::parse(number), Ok(num));
+            assert_eq!(AtticNumeral::parse(drachmas), Ok(num));
+            assert_eq!(AtticNumeral::parse(talents), Ok(num));
+        }
+        let unit = |s: &str| AtticNumeral::parse_with_unit(s).map(|(_, unit)| unit);
+        assert_eq!(unit("ΔΠΙΙ"), Ok(AtticUnit::Number));
+        assert_eq!(unit("ΔΠ𐅂𐅂"), Ok(AtticUnit::Drachmas));
+        assert_eq!(unit("𐅉𐅈ΤΤ"), Ok(AtticUnit::Talents));
+        // Without ones, a sum in drachmas cannot be told apart from a number
+        assert_eq!(unit("𐅆𐅅"), Ok(AtticUnit::Number));
+        assert_eq!(unit("𐅇Μ"), Ok(AtticUnit::Number));
+        for value in AtticNumeral::MIN..=AtticNumeral::MAX {
+            let num = AtticNumeral(value);
+            assert_eq!(num.to_string().parse(), Ok(num));
+        }
+
+        // The Attic five from the Ancient Greek Numbers block, and regional signs
+        assert_eq!(AtticNumeral::parse("𐅃ΙΙ"), Ok(AtticNumeral(7)));
+        assert_eq!(AtticNumeral::parse("𐅪𐅤𐅙"), Ok(AtticNumeral(111)));
+        assert_eq!(AtticNumeral::parse("\u{1016c}"), Ok(AtticNumeral(500)));
+        assert_eq!(AtticNumeral::parse("\u{1016c}𐅪"), Ok(AtticNumeral(600)));
+        assert_eq!(AtticNumeral::parse("\u{1016b}𐅥𐅜"), Ok(AtticNumeral(332)));
+        assert_eq!(AtticNumeral::parse("Η\u{1016b}"), Ok(AtticNumeral(400)));
+
+        let error = |s: &str| {
+            let err = AtticNumeral::parse(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("ΔX"), (ParseErrorKind::InvalidCharacter, 2));
+        assert_eq!(error("ΙΔ"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("ΠΠ"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("ΔΙΙΙΙΙ"), (ParseErrorKind::NonCanonical, 2));
+        assert_eq!(error("\u{1016b}ΗΗ"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("𐅜ΙΙΙ"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("Δ𐅂Ι"), (ParseErrorKind::NonCanonical, 6));
+        assert_eq!(error("𐅉Δ"), (ParseErrorKind::NonCanonical, 4));
+        assert_eq!(error("𐅇𐅇"), (ParseErrorKind::OutOfRange, 0));
+    }
+
+    #[test]
+    fn test_hebrew_numeral() {
+        assert_eq!(HebrewNumeral::new(0), Err(OutOfRangeError));
+        assert_eq!(HebrewNumeral::new(1_000_000), Err(OutOfRangeError));
+        assert_eq!(HebrewNumeral::try_from(-1_i16), Err(OutOfRangeError));
+        assert_eq!(HebrewNumeral::try_from(18_usize), Ok(HebrewNumeral(18)));
+
+        let plain = HebrewStyle::new().punctuation(false);
+        let finals = HebrewStyle::new().final_forms(true);
+        let formats = [
+            (1, "א׳", "א", "א׳"),
+            (15, "ט״ו", "טו", "ט״ו"),
+            (16, "ט״ז", "טז", "ט״ז"),
+            (17, "י״ז", "יז", "י״ז"),
+            (100, "ק׳", "ק", "ק׳"),
+            (115, "קט״ו", "קטו", "קט״ו"),
+            (500, "ת״ק", "תק", "ך׳"),
+            (616, "תרט״ז", "תרטז", "םט״ז"),
+            (999, "תתקצ״ט", "תתקצט", "ץצ״ט"),
+            (5000, "ה׳ אלפים", "ה׳ אלפים", "ה׳ אלפים"),
+            (5784, "ה׳תשפ״ד", "ה׳תשפד", "ה׳ןפ״ד"),
+            (15_016, "טו׳ט״ז", "טו׳טז", "טו׳ט״ז"),
+            (
+                HebrewNumeral::MAX,
+                "תתקצט׳תתקצ״ט",
+                "תתקצט׳תתקצט",
+                "ץצט׳ץצ״ט",
+            ),
+        ];
+        for (value, punctuated, unpunctuated, final_forms) in formats {
+            let num = HebrewNumeral(value);
+            assert_eq!(num.to_string(), punctuated);
+            assert_eq!(num.format_with(&plain), unpunctuated);
+            assert_eq!(num.format_with(&finals), final_forms);
+            assert_eq!(HebrewNumeral::parse(punctuated), Ok(num));
+            assert_eq!(HebrewNumeral::parse(unpunctuated), Ok(num));
+            assert_eq!(HebrewNumeral::parse(final_forms), Ok(num));
+        }
+        for value in (HebrewNumeral::MIN..10_000).chain((10_000..=HebrewNumeral::MAX).step_by(97)) {
+            let num = HebrewNumeral(value);
+            assert_eq!(num.to_string().parse(), Ok(num));
+        }
+        assert_eq!(HebrewNumeral::parse("ה'תשפ\"ד"), Ok(HebrewNumeral(5784)));
+
+        let error = |s: &str| {
+            let err = HebrewNumeral::parse(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("יX"), (ParseErrorKind::InvalidCharacter, 2));
+        assert_eq!(error("י״ה"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("ד״ש"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("תשפד״"), (ParseErrorKind::NonCanonical, 8));
+        assert_eq!(error("׳"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("תתתת׳א"), (ParseErrorKind::OutOfRange, 0));
+    }
+
+    #[test]
+    fn test_cyrillic_numeral() {
+        assert_eq!(CyrillicNumeral::new(0), Err(OutOfRangeError));
+        assert_eq!(CyrillicNumeral::new(1_000_000_000), Err(OutOfRangeError));
+        assert_eq!(CyrillicNumeral::try_from(-7_i8), Err(OutOfRangeError));
+        assert_eq!(CyrillicNumeral::try_from(7_u128), Ok(CyrillicNumeral(7)));
+
+        let plain = CyrillicStyle::new().titlo(false);
+        let formats = [
+            (1, "а҃", "а"),
+            (11, "а҃і", "аі"),
+            (15, "є҃і", "єі"),
+            (20, "к҃", "к"),
+            (42, "м҃в", "мв"),
+            (111, "ра҃і", "раі"),
+            (999, "цч҃ѳ", "цчѳ"),
+            (1000, "҂а҃", "҂а"),
+            (1706, "҂аѱ҃ѕ", "҂аѱѕ"),
+            (7019, "҂зѳ҃і", "҂зѳі"),
+            (30_000, "г҃\u{20dd}", "г\u{20dd}"),
+            (200_001, "в҃\u{488}а", "в\u{488}а"),
+            (
+                CyrillicNumeral::MAX,
+                "ѳ\u{a671}ѳ\u{a670}ѳ\u{489}ѳ\u{488}ѳ\u{20dd}҂ѳцч҃ѳ",
+                "ѳ\u{a671}ѳ\u{a670}ѳ\u{489}ѳ\u{488}ѳ\u{20dd}҂ѳцчѳ",
+            ),
+        ];
+        for (value, titlo, bare) in formats {
+            let num = CyrillicNumeral(value);
+            assert_eq!(num.to_string(), titlo);
+            assert_eq!(num.format_with(&plain), bare);
+            assert_eq!(CyrillicNumeral::parse(titlo), Ok(num));
+            assert_eq!(CyrillicNumeral::parse(bare), Ok(num));
+        }
+        for value in (1..100_000).chain((100_000..=CyrillicNumeral::MAX).step_by(99_991)) {
+            let num = CyrillicNumeral(value);
+            assert_eq!(num.to_string().parse(), Ok(num));
+            assert_eq!(CyrillicNumeral::parse(&num.format_with(&plain)), Ok(num));
+        }
+        assert_eq!(CyrillicNumeral::parse("ѿ҃е"), Ok(CyrillicNumeral(805)));
+        assert_eq!(CyrillicNumeral::parse("у҃ѻ"), Ok(CyrillicNumeral(470)));
+
+        let error = |s: &str| {
+            let err = CyrillicNumeral::parse(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("а҃b"), (ParseErrorKind::InvalidCharacter, 4));
+        assert_eq!(error("ів"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("і҃а"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("мв҃"), (ParseErrorKind::NonCanonical, 2));
+        assert_eq!(error("҃"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("ц\u{a671}ѳ"), (ParseErrorKind::OutOfRange, 0));
+    }
+
+    #[test]
+    fn test_etruscan_numeral() {
+        assert_eq!(EtruscanNumeral::new(0), Err(OutOfRangeError));
+        assert_eq!(EtruscanNumeral::new(500), Err(OutOfRangeError));
+        assert_eq!(EtruscanNumeral::try_from(-27_i32), Err(OutOfRangeError));
+        assert_eq!(EtruscanNumeral::try_from(27_u16), Ok(EtruscanNumeral(27)));
+
+        let formats = [
+            (1, "𐌠", "𐌠", "I"),
+            (4, "𐌠𐌠𐌠𐌠", "𐌠𐌠𐌠𐌠", "IIII"),
+            (9, "𐌠𐌠𐌠𐌠𐌡", "𐌡𐌠𐌠𐌠𐌠", "VIIII"),
+            (27, "𐌠𐌠𐌡𐌢𐌢", "𐌢𐌢𐌡𐌠𐌠", "XXVII"),
+            (160, "𐌢𐌣𐌟", "𐌟𐌣𐌢", "CLX"),
+            (
+                EtruscanNumeral::MAX,
+                "𐌠𐌠𐌠𐌠𐌡𐌢𐌢𐌢𐌢𐌣𐌟𐌟𐌟𐌟",
+                "𐌟𐌟𐌟𐌟𐌣𐌢𐌢𐌢𐌢𐌡𐌠𐌠𐌠𐌠",
+                "CCCCLXXXXVIIII",
+            ),
+        ];
+        for (value, right_to_left, left_to_right, roman) in formats {
+            let num = EtruscanNumeral(value);
+            assert_eq!(num.to_string(), right_to_left);
+            assert_eq!(num.format_with(Direction::LeftToRight), left_to_right);
+            assert_eq!(
+                num.glyphs().map(|(_, letter)| letter).collect::<String>(),
+                roman
+            );
+            assert_eq!(
+                RomanNumeral::from(num)
+                    .format_with(&NumeralStyle::new().additive(AdditiveForms::ALL)),
+                Ok(roman.to_string())
+            );
+            assert_eq!(EtruscanNumeral::parse(right_to_left), Ok(num));
+            assert_eq!(EtruscanNumeral::parse(left_to_right), Ok(num));
+        }
+        for value in EtruscanNumeral::MIN..=EtruscanNumeral::MAX {
+            let num = EtruscanNumeral(value);
+            assert_eq!(num.to_string().parse(), Ok(num));
+            assert_eq!(num.format_with(Direction::LeftToRight).parse(), Ok(num));
+        }
+        assert_eq!(
+            EtruscanNumeral::parse_with_direction("𐌢𐌡"),
+            Ok((EtruscanNumeral(15), Direction::LeftToRight))
+        );
+        assert_eq!(
+            EtruscanNumeral::parse_with_direction("𐌢𐌢"),
+            Ok((EtruscanNumeral(20), Direction::RightToLeft))
+        );
+
+        let error = |s: &str| {
+            let err = EtruscanNumeral::parse(s).unwrap_err();
+            (err.kind(), err.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("𐌢X"), (ParseErrorKind::InvalidCharacter, 4));
+        assert_eq!(error("𐌠𐌢𐌠"), (ParseErrorKind::NonCanonical, 4));
+        assert_eq!(error("𐌡𐌡"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("𐌠𐌠𐌠𐌠𐌠"), (ParseErrorKind::NonCanonical, 0));
+        assert_eq!(error("𐌟𐌟𐌟𐌟𐌟"), (ParseErrorKind::OutOfRange, 0));
+    }
+
+    #[test]
+    fn test_numeral_system() {
+        // Styles which parse_numeral reads back, as the Roman parser only accepts canonical forms
+        fn round_trip<N: NumeralSystem>(parsed: &[&str]) {
+            for &(name, style) in N::STYLES {
+                assert_eq!(N::style(name), Some(style));
+                assert_eq!(N::style_name(&style), Some(name));
+                for value in [N::MIN, N::MIN + 1, 1984.min(N::MAX), N::MAX - 1, N::MAX] {
+                    let num = N::from_value(value).unwrap();
+                    assert_eq!(num.value(), value);
+                    let text = num.format_styled(&style).unwrap();
+                    if parsed.contains(&name) {
+                        assert_eq!(N::parse_numeral(&text), Ok(num));
+                    }
+                    assert_eq!(N::parse_styled(&text, &style), Ok(num), "{name} {text}");
+                }
+            }
+            assert_eq!(N::from_value(N::MAX + 1), Err(OutOfRangeError));
+            assert_eq!(N::style("unknown"), None);
+        }
+        round_trip::<RomanNumeral>(&[
+            "uppercase",
+            "lowercase",
+            "double-vinculum",
+            "framed",
+            "number-forms",
+        ]);
+        round_trip::<GreekNumeral>(&[
+            "uppercase",
+            "lowercase",
+            "diophantus",
+            "diophantus-lowercase",
+        ]);
+        round_trip::<AtticNumeral>(&["number", "drachmas", "talents"]);
+        round_trip::<HebrewNumeral>(&["punctuated", "plain", "final-forms"]);
+        round_trip::<CyrillicNumeral>(&["titlo", "plain"]);
+        round_trip::<EtruscanNumeral>(&["right-to-left", "left-to-right"]);
+        assert_eq!(RomanNumeral::value_range(), MIN..=MAX);
+        assert_eq!(AtticNumeral::value_range(), 1..=99_999);
+
+        let format: NumeralFormat = "roman".parse().unwrap();
+        assert_eq!(format, NumeralFormat::Roman(NumeralStyle::UPPERCASE));
+        assert_eq!(format.format(1984), Ok("MCMLXXXIV".to_string()));
+        assert_eq!(format.parse("MCMLXXXIV"), Ok(1984));
+        assert_eq!(format.format(MAX + 1), Err(OutOfRangeError));
+        for &(name, style) in RomanNumeral::STYLES {
+            let format = NumeralFormat::Roman(style);
+            for value in (0..5000).chain((5000..=MAX).step_by(9_999_991)) {
+                let text = format.format(value).unwrap();
+                assert_eq!(format.parse(&text), Ok(value), "{name} {text}");
+            }
+        }
+        let parse = |config: &str, s: &str| config.parse::<NumeralFormat>().unwrap().parse(s);
+        assert_eq!(parse("roman:additive", "MDCCCCLXXXXVIIII"), Ok(1999));
+        assert_eq!(parse("roman:clock", "IIII"), Ok(4));
+        assert_eq!(parse("roman:clock", "IX"), Ok(9));
+        assert_eq!(parse("roman:apostrophus", "CIↃIↃCXXV"), Ok(1625));
+        assert_eq!(parse("roman:apostrophus", "CCCCIↃↃↃↃ"), Ok(1_000_000));
+        assert_eq!(parse("roman:ascii", "_I_VCMXCIX"), Ok(4999));
+        assert_eq!(parse("roman:double-vinculum", "I̿V̿"), Ok(4_000_000));
+        assert_eq!(parse("roman", "XLII"), Ok(42));
+        assert_eq!(
+            parse("roman:clock", "IIIII").unwrap_err().kind(),
+            ParseErrorKind::NonCanonical
+        );
+        assert_eq!(parse("roman:additive", "IV"), Ok(4));
+        let nulla = NumeralStyle::new().zero(Zero::Nulla);
+        assert_eq!(
+            RomanNumeral::parse_with("nulla", &nulla),
+            Ok(RomanNumeral(0))
+        );
+        assert_eq!(RomanNumeral::parse_with("N", &nulla), Ok(RomanNumeral(0)));
+        let scribe = NumeralStyle::LOWERCASE.manuscript(Manuscript::ALL);
+        assert_eq!(
+            RomanNumeral::parse_with(".xꝟ.", &scribe),
+            Ok(RomanNumeral(15))
+        );
+
+        let format: NumeralFormat = "greek:diophantus".parse().unwrap();
+        assert_eq!(
+            (format.system(), format.style_name()),
+            ("greek", Some("diophantus"))
+        );
+        assert_eq!(format.format(20_000), Ok("Β.ʹ".to_string()));
+        assert_eq!(format.range(), 0..=99_999_999);
+
+        let format: NumeralFormat = "attic:talents".parse().unwrap();
+        assert_eq!(format.format(17), Ok("𐅉𐅈ΤΤ".to_string()));
+        assert_eq!(format.format(0), Err(OutOfRangeError));
+        assert_eq!(format.parse("ΔΠΙΙ"), Ok(17));
+        let mut buf = String::new();
+        assert!(format.write(100_000, &mut buf).is_err());
+
+        let style = NumeralStyle::new().case(Case::Lower).v_letter(VLetter::V);
+        assert_eq!(NumeralFormat::Roman(style).style_name(), None);
+        for system in NumeralFormat::SYSTEMS {
+            assert_eq!(system.parse::<NumeralFormat>().unwrap().system(), system);
+        }
+        for config in [
+            "",
+            "latin",
+            "roman:",
+            "roman:italic",
+            "Roman",
+            "greek:number",
+        ] {
+            assert_eq!(config.parse::<NumeralFormat>(), Err(ParseFormatError));
+        }
+    }
+
+    #[test]
+    fn test_roman_numeral_round_trip() {
+        for value in (MIN..10_000)
+            .chain((10_000..5_000_000).step_by(997))
+            .chain((5_000_000..=MAX).step_by(999_983))
+        {
+            let num = RomanNumeral(value);
+            assert_eq!(RomanNumeral::parse(&num.to_uppercase()), Ok(num));
+            assert_eq!(RomanNumeral::parse(&num.to_lowercase()), Ok(num));
+            let forms = num.format_with(&NumeralStyle::new().letters(Letters::NumberForms));
+            assert_eq!(RomanNumeral::parse(&forms.unwrap()), Ok(num));
+        }
+    }
+
+    #[test]
+    #[ignore = "exhaustive, run with --ignored"]
+    fn test_roman_numeral_round_trip_exhaustive() {
+        for value in MIN..5_000_000 {
+            let num = RomanNumeral(value);
+            assert_eq!(RomanNumeral::parse(&num.to_uppercase()), Ok(num));
+            assert_eq!(RomanNumeral::parse(&num.to_lowercase()), Ok(num));
+        }
+    }
+
+    #[test]
+    fn test_roman_numeral_parse_lenient() {
+        let num = RomanNumeral::parse_lenient("XLII").unwrap();
+        assert_eq!(num.value, RomanNumeral(42));
+        assert!(num.is_canonical());
+
+        let num = RomanNumeral::parse_lenient("xiiij").unwrap();
+        assert_eq!(num.value, RomanNumeral(14));
+        assert_eq!(
+            num.deviations,
+            vec![
+                Deviation {
+                    kind: DeviationKind::Additive,
+                    span: 1..5
+                },
+                Deviation {
+                    kind: DeviationKind::LetterJ,
+                    span: 4..5
+                },
+            ]
+        );
+
+        let num = RomanNumeral::parse_lenient("MDCCCCLXXXXVIIII").unwrap();
+        assert_eq!(num.value, RomanNumeral(1999));
+        assert_eq!(num.deviations.len(), 3);
+        assert!(
+            num.deviations
+                .iter()
+                .all(|deviation| deviation.kind == DeviationKind::Additive)
+        );
+
+        let num = RomanNumeral::parse_lenient("IIX").unwrap();
+        assert_eq!(num.value, RomanNumeral(8));
+        assert_eq!(num.deviations[0].kind, DeviationKind::IrregularSubtractive);
+        assert_eq!(num.deviations[0].span, 0..3);
+        let num = RomanNumeral::parse_lenient("IC").unwrap();
+        assert_eq!(num.value, RomanNumeral(99));
+        assert_eq!(num.deviations[0].kind, DeviationKind::IrregularSubtractive);
+
+        let num = RomanNumeral::parse_lenient("XVI").unwrap();
+        assert!(num.is_canonical());
+        let num = RomanNumeral::parse_lenient("XUI").unwrap();
+        assert_eq!(num.value, RomanNumeral(16));
+        assert_eq!(num.deviations[0].kind, DeviationKind::LetterU);
+        let num = RomanNumeral::parse_lenient("xvi").unwrap();
+        assert_eq!(num.deviations[0].kind, DeviationKind::LetterU);
+        let num = RomanNumeral::parse_lenient("xui").unwrap();
+        assert!(num.is_canonical());
+
+        let num = RomanNumeral::parse_lenient("XiV").unwrap();
+        assert_eq!(num.value, RomanNumeral(14));
+        assert_eq!(num.deviations[0].kind, DeviationKind::MixedCase);
+        assert_eq!(num.deviations[0].span, 1..2);
+
+        let num = RomanNumeral::parse_lenient("_I_V").unwrap();
+        assert_eq!(num.value, RomanNumeral(4000));
+        assert_eq!(num.deviations.len(), 2);
+        assert_eq!(num.deviations[1].kind, DeviationKind::OverbarSubstitute);
+        assert_eq!(num.deviations[1].span, 2..4);
+        let num = RomanNumeral::parse_lenient("V\u{304}MM").unwrap();
+        assert_eq!(num.value, RomanNumeral(7000));
+        assert_eq!(num.deviations[0].kind, DeviationKind::OverbarSubstitute);
+        assert_eq!(num.deviations[0].span, 0..3);
+
+        let num = RomanNumeral::parse_lenient("VV").unwrap();
+        assert_eq!(num.value, RomanNumeral(10));
+        assert_eq!(num.deviations[0].kind, DeviationKind::NonCanonical);
+
+        let err = RomanNumeral::parse_lenient("XIZ").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::InvalidCharacter, 2)
+        );
+    }
+
+    #[test]
+    fn test_roman_numeral_to_apostrophus() {
+        let year = RomanNumeral::new(1625).unwrap();
+        assert_eq!(year.to_apostrophus(Apostrophus::Unicode), "CIↃIↃCXXV");
+        assert_eq!(year.to_apostrophus(Apostrophus::Ligatures), "ↀIↃCXXV");
+        assert_eq!(year.to_apostrophus(Apostrophus::Ascii), "CI)I)CXXV");
+
+        let apostrophus = |value: u64| RomanNumeral(value).to_apostrophus(Apostrophus::Unicode);
+        assert_eq!(apostrophus(0), "N");
+        assert_eq!(apostrophus(49), "XLIX");
+        assert_eq!(apostrophus(900), "IↃCCCC");
+        assert_eq!(apostrophus(1900), "CIↃIↃCCCC");
+        assert_eq!(apostrophus(5000), "IↃↃ");
+        assert_eq!(apostrophus(10_000), "CCIↃↃ");
+        assert_eq!(apostrophus(50_000), "IↃↃↃ");
+        assert_eq!(apostrophus(100_000), "CCCIↃↃↃ");
+        assert_eq!(apostrophus(1_000_000), "CCCCIↃↃↃↃ");
+        assert_eq!(
+            RomanNumeral(165_000).to_apostrophus(Apostrophus::Ligatures),
+            "ↈↇↂↁ"
+        );
+    }
+
+    #[test]
+    fn test_roman_numeral_to_additive() {
+        let num = RomanNumeral::new(1914).unwrap();
+        assert_eq!(num.to_additive(AdditiveForms::ALL), "MDCCCCXIIII");
+        assert_eq!(num.to_additive(AdditiveForms::CLOCK), "MCMXIIII");
+        assert_eq!(num.to_additive(AdditiveForms::NONE), "MCMXIV");
+        assert_eq!(num.to_additive(AdditiveForms::NONE.nines(2)), "MDCCCCXIV");
+        assert_eq!(RomanNumeral(9).to_additive(AdditiveForms::CLOCK), "IX");
+        assert_eq!(RomanNumeral(0).to_additive(AdditiveForms::ALL), "N");
+        assert_eq!(RomanNumeral(4000).to_additive(AdditiveForms::ALL), "MMMM");
+        assert_eq!(RomanNumeral(9000).to_additive(AdditiveForms::ALL), "V̅MMMM");
+        assert_eq!(
+            RomanNumeral(4_999_999).to_additive(AdditiveForms::ALL),
+            "M̅M̅M̅M̅D̅C̅C̅C̅C̅L̅X̅X̅X̅X̅V̅MMMMDCCCCLXXXXVIIII"
+        );
+        assert_eq!(
+            RomanNumeral(MAX).to_additive(AdditiveForms::ALL),
+            "M̿M̿M̿M̿D̿C̿C̿C̿C̿L̿X̿X̿X̿X̿V̿M̅M̅M̅M̅D̅C̅C̅C̅C̅L̅X̅X̅X̅X̅V̅MMMMDCCCCLXXXXVIIII"
+        );
+
+        for value in (MIN..10_000)
+            .chain((10_000..5_000_000).step_by(991))
+            .chain((5_000_000..=MAX).step_by(999_983))
+        {
+            let num = RomanNumeral(value);
+            assert_eq!(num.to_additive(AdditiveForms::NONE), num.to_uppercase());
+        }
+    }
+
+    #[test]
+    fn test_roman_numeral_format_with() {
+        for value in (MIN..10_000)
+            .chain((10_000..5_000_000).step_by(997))
+            .chain((5_000_000..=MAX).step_by(999_983))
+        {
+            let num = RomanNumeral(value);
+            assert_eq!(
+                num.format_with(&NumeralStyle::UPPERCASE),
+                Ok(num.to_uppercase())
+            );
+            assert_eq!(
+                num.format_with(&NumeralStyle::LOWERCASE),
+                Ok(num.to_lowercase())
+            );
+        }
+
+        let zero = RomanNumeral(0);
+        assert_eq!(zero.format_with(&NumeralStyle::LOWERCASE).unwrap(), "N");
+        let nulla = NumeralStyle::new().zero(Zero::Nulla);
+        assert_eq!(zero.format_with(&nulla).unwrap(), "nulla");
+        let strict = NumeralStyle::new().zero(Zero::Error);
+        assert_eq!(zero.format_with(&strict), Err(OutOfRangeError));
+        assert_eq!(RomanNumeral(1).format_with(&strict).unwrap(), "I");
+
+        let num = RomanNumeral(1984);
+        let clock = NumeralStyle::new()
+            .case(Case::Lower)
+            .additive(AdditiveForms::CLOCK)
+            .v_letter(VLetter::V);
+        assert_eq!(num.format_with(&clock).unwrap(), "mcmlxxxiiii");
+        let upper_u = NumeralStyle::new().v_letter(VLetter::U);
+        assert_eq!(RomanNumeral(15).format_with(&upper_u).unwrap(), "XU");
+
+        let num = RomanNumeral(6_004);
+        let ascii = NumeralStyle::new().large(LargeNumerals::AsciiVinculum);
+        assert_eq!(num.format_with(&ascii).unwrap(), "_VMIV");
+        let precomposed = NumeralStyle::new().overbar(Overbar::Precomposed);
+        assert_eq!(
+            RomanNumeral(4_000).format_with(&precomposed).unwrap(),
+            "ĪV\u{304}"
+        );
+        let lower_precomposed = precomposed.case(Case::Lower);
+        assert_eq!(
+            RomanNumeral(4_000).format_with(&lower_precomposed).unwrap(),
+            "īū"
+        );
+
+        let renaissance = NumeralStyle::new()
+            .case(Case::Lower)
+            .large(LargeNumerals::Apostrophus(Apostrophus::Unicode));
+        assert_eq!(
+            RomanNumeral(1625).format_with(&renaissance).unwrap(),
+            "ciↄiↄcxxu"
+        );
+    }
+
+    #[test]
+    fn test_roman_numeral_write_with() {
+        use core::fmt::Write;
+
+        let mut out = String::new();
+        RomanNumeral(616)
+            .write_with(&NumeralStyle::LOWERCASE, &mut out)
+            .unwrap();
+        write!(out, " {}", RomanNumeral(1984)).unwrap();
+        assert_eq!(out, "dcxui MCMLXXXIV");
+
+        let mut buf = [0_u8; 64];
+        let roman = RomanNumeral(999_999)
+            .format_into(&NumeralStyle::UPPERCASE, &mut buf)
+            .unwrap();
+        assert_eq!(roman, "C̅M̅X̅C̅I̅X̅CMXCIX");
+        let mut small = [0_u8; 4];
+        assert!(
+            RomanNumeral(3_888)
+                .format_into(&NumeralStyle::UPPERCASE, &mut small)
+                .is_err()
+        );
+        let strict = NumeralStyle::new().zero(Zero::Error);
+        assert!(RomanNumeral(0).format_into(&strict, &mut buf).is_err());
+    }
+
     #[test]
     fn test_kalendarium_dates() {
         // The day of "Wish World" S2:E7
@@ -0,0 +1,309 @@
+//! # kalendarium manuscript numerals
+//!
+//! Medieval scribes wrote Roman numerals in ways of their own, which a diplomatic transcription
+//! has to keep. The last i of a numeral became a j, as in iiij for 4 and vij for 7, so that
+//! nothing could be added after it. Thousands and hundreds were counted with a superscript m or
+//! c, as in vᵐ for 5000 and ijᶜ for 200, numerals were set off from the text with dots, as in
+//! .xx., and some hands wrote five with a stroked v (ꝟ). ``Manuscript`` chooses which of these
+//! a ``NumeralStyle`` writes, and ``RomanNumeral::parse_manuscript`` reads them back.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::numeral::{Canonical, DOUBLE, VINCULUM};
+use crate::{
+    AdditiveForms, Case, LargeNumerals, MAX, NumeralStyle, ParseErrorKind, ParseNumeralError,
+    Result, RomanNumeral, VLetter,
+};
+use core::fmt::{self, Write};
+
+/// Which manuscript conventions a numeral is written with
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct Manuscript {
+    terminal_j: bool,
+    superscripts: bool,
+    dots: bool,
+    stroked_v: bool,
+}
+
+impl Manuscript {
+    /// No manuscript conventions, as in modern print
+    pub const NONE: Manuscript = Manuscript {
+        terminal_j: false,
+        superscripts: false,
+        dots: false,
+        stroked_v: false,
+    };
+    /// Every manuscript convention, e.g. .ꝟᵐiijᶜlxꝟij. for 5367
+    pub const ALL: Manuscript = Manuscript {
+        terminal_j: true,
+        superscripts: true,
+        dots: true,
+        stroked_v: true,
+    };
+
+    /// Sets whether the last i of the numeral, and of each count of thousands or hundreds, is
+    /// written j, e.g. iiij for 4. A numeral of a single i is left alone.
+    #[must_use]
+    pub const fn terminal_j(self, terminal_j: bool) -> Self {
+        Manuscript { terminal_j, ..self }
+    }
+
+    /// Sets whether thousands and hundreds are counted with a superscript m and c, e.g. vᵐ for
+    /// 5000 and ijᶜ for 200. A single thousand or hundred is still written m or c.
+    #[must_use]
+    pub const fn superscripts(self, superscripts: bool) -> Self {
+        Manuscript {
+            superscripts,
+            ..self
+        }
+    }
+
+    /// Sets whether the numeral is set off with a dot on either side, e.g. .xx.
+    #[must_use]
+    pub const fn dots(self, dots: bool) -> Self {
+        Manuscript { dots, ..self }
+    }
+
+    /// Sets whether five is written with the stroked v (ꝟ, U+A75F), e.g. xꝟ for 15.
+    #[must_use]
+    pub const fn stroked_v(self, stroked_v: bool) -> Self {
+        Manuscript { stroked_v, ..self }
+    }
+
+    /// Helper function for whether five is written with the stroked v
+    pub(crate) const fn has_stroked_v(self) -> bool {
+        self.stroked_v
+    }
+}
+
+/// Helper writer which holds back the last character written, so that a final i can become a j
+struct TerminalJ<'w, W: Write> {
+    out: &'w mut W,
+    enabled: bool,
+    pending: Option<char>,
+    letters: u32,
+}
+
+impl<W: Write> TerminalJ<'_, W> {
+    /// Helper function to write the held back character, as a j if it ends a count of letters
+    fn finish(&mut self) -> fmt::Result {
+        match self.pending.take() {
+            Some('i') if self.enabled && self.letters > 1 => self.out.write_char('j'),
+            Some('I') if self.enabled && self.letters > 1 => self.out.write_char('J'),
+            Some(pending) => self.out.write_char(pending),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Write for TerminalJ<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if let Some(pending) = self.pending.take() {
+            self.out.write_char(pending)?;
+        }
+        if c.is_alphabetic() {
+            self.letters += 1;
+        }
+        self.pending = Some(c);
+        Ok(())
+    }
+}
+
+impl NumeralStyle {
+    /// Helper function to write a non-zero number with the manuscript conventions of the style
+    pub(crate) fn write_manuscript<W: Write>(&self, num: u64, out: &mut W) -> fmt::Result {
+        let manuscript = self.manuscript_forms();
+        if manuscript.dots {
+            out.write_char('.')?;
+        }
+        let mut terminal = TerminalJ {
+            out,
+            enabled: manuscript.terminal_j,
+            pending: None,
+            letters: 0,
+        };
+        if manuscript.superscripts {
+            self.write_superscripts(num, &mut terminal)?;
+        } else {
+            self.write(num, &mut terminal)?;
+        }
+        terminal.finish()?;
+        if manuscript.dots {
+            terminal.out.write_char('.')?;
+        }
+        Ok(())
+    }
+
+    /// Helper function to write a number with its thousands and hundreds counted by a
+    /// superscript m and c
+    fn write_superscripts<W: Write>(&self, num: u64, out: &mut TerminalJ<W>) -> fmt::Result {
+        // A single thousand or hundred is written as usual, with m or c
+        let thousands = num / 1000;
+        if thousands > 1 {
+            self.write_superscripts(thousands, out)?;
+            out.finish()?;
+            out.write_char(THOUSANDS)?;
+        } else if thousands == 1 {
+            self.write(1000, out)?;
+        }
+        let hundreds = num / 100 % 10;
+        if hundreds > 1 {
+            self.write(hundreds, out)?;
+            out.finish()?;
+            out.write_char(HUNDREDS)?;
+        } else if hundreds == 1 {
+            self.write(100, out)?;
+        }
+        let rest = num % 100;
+        if rest > 0 {
+            self.write(rest, out)?;
+        }
+        Ok(())
+    }
+}
+
+impl RomanNumeral {
+    /// Parses a ``RomanNumeral`` written with the manuscript conventions of ``Manuscript``.
+    ///
+    /// Accepted are the forms written by a ``NumeralStyle`` with any ``Manuscript`` conventions,
+    /// in either case, with u or v for five, and with the fours and nines either all additive or
+    /// all subtractive. Numerals of 4000 and more are written with superscripts, or with the
+    /// vinculum as a style without superscripts writes them, and zero is N or nulla. For any other
+    /// spelling, use ``parse_lenient``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: RomanNumeral = RomanNumeral::parse_manuscript(".vᵐiijᶜlxviij.")?;
+    ///    assert_eq!(num.as_u64(), 5368);
+    ///
+    pub fn parse_manuscript(s: &str) -> Result<Self, ParseNumeralError> {
+        let dots = s.len() > 1 && s.starts_with('.') && s.ends_with('.');
+        let letters = if dots { &s[1..s.len() - 1] } else { s };
+        let offset = if dots { 1 } else { 0 };
+        if letters.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        if s == "N" || s == "nulla" {
+            return Ok(RomanNumeral(0));
+        }
+
+        // A superscript m multiplies everything before it, e.g. xx ᵐ for 20,000, and a
+        // superscript c the count of hundreds just before it, e.g. m ij ᶜ for 1200
+        let mut total: u64 = 0;
+        let mut hundreds: u64 = 0;
+        let mut group: i64 = 0;
+        let mut count_start: Option<i64> = None;
+        let mut previous: i64 = 0;
+        let mut uppercase = None;
+        let mut chars = letters.char_indices().peekable();
+        while let Some((position, character)) = chars.next() {
+            let mut symbol = match character {
+                THOUSANDS => {
+                    total = total
+                        .saturating_add(hundreds)
+                        .saturating_add(group.max(0) as u64)
+                        .saturating_mul(1000);
+                    (hundreds, group, count_start, previous) = (0, 0, None, 0);
+                    continue;
+                }
+                HUNDREDS => {
+                    let start = count_start.unwrap_or(group);
+                    let count = (group - start).max(0) as u64;
+                    hundreds = hundreds.saturating_add(count.saturating_mul(100));
+                    (group, count_start, previous) = (start, None, 0);
+                    continue;
+                }
+                'j' | 'J' => 1,
+                'ꝟ' | 'Ꝟ' => 5,
+                _ => match Self::symbol_value(character) {
+                    Some(value) if character.is_ascii() => i64::from(value),
+                    _ => {
+                        return Err(ParseNumeralError::new(
+                            ParseErrorKind::InvalidCharacter,
+                            offset + position,
+                        ));
+                    }
+                },
+            };
+            uppercase.get_or_insert(character.is_uppercase());
+            while let Some((_, bar)) = chars.next_if(|&(_, c)| c == VINCULUM || c == DOUBLE) {
+                let bars = if bar == DOUBLE { 1_000_000 } else { 1000 };
+                symbol = symbol.saturating_mul(bars);
+            }
+            // A count of hundreds is written with the letters up to ten
+            if symbol > 10 {
+                count_start = None;
+            } else if count_start.is_none() {
+                count_start = Some(group);
+            }
+            if previous < symbol {
+                group -= 2 * previous;
+            }
+            group = group.saturating_add(symbol);
+            previous = symbol;
+        }
+        let value = total
+            .saturating_add(hundreds)
+            .saturating_add(group.max(0) as u64);
+        if value > MAX {
+            return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0));
+        }
+
+        let manuscript = Manuscript {
+            terminal_j: letters.contains(['j', 'J']),
+            superscripts: letters.contains([THOUSANDS, HUNDREDS]),
+            dots,
+            stroked_v: letters.contains(['ꝟ', 'Ꝟ']),
+        };
+        let case = if uppercase == Some(true) {
+            Case::Upper
+        } else {
+            Case::Lower
+        };
+        let v_letter = if letters.contains(['v', 'V']) {
+            VLetter::V
+        } else {
+            VLetter::U
+        };
+        let mut matched = 0;
+        let forms = [AdditiveForms::ALL, AdditiveForms::NONE]
+            .into_iter()
+            .flat_map(|additive| {
+                [LargeNumerals::Vinculum, LargeNumerals::DoubleVinculum]
+                    .map(|large| (additive, large))
+            });
+        for (additive, large) in forms {
+            let style = NumeralStyle::new()
+                .case(case)
+                .v_letter(v_letter)
+                .additive(additive)
+                .large(large)
+                .manuscript(manuscript);
+            let mut canonical = Canonical::new(s);
+            let result = RomanNumeral(value).write_with(&style, &mut canonical);
+            if result.is_ok() && canonical.is_complete() {
+                return Ok(RomanNumeral(value));
+            }
+            matched = matched.max(canonical.matched);
+        }
+        Err(ParseNumeralError::new(
+            ParseErrorKind::NonCanonical,
+            matched,
+        ))
+    }
+}
+
+/// The superscript m, which multiplies the letters before it by 1000
+const THOUSANDS: char = 'ᵐ';
+
+/// The superscript c, which multiplies the letters before it by 100
+const HUNDREDS: char = 'ᶜ';
@@ -0,0 +1,356 @@
+//! # kalendarium markup for the vinculum
+//!
+//! The vinculum is written with a combining overline (U+0305) after each letter, or U+033F for
+//! the double vinculum, and many fonts draw these badly or not at all. ``Markup`` rewrites the
+//! barred letters of a numeral, or of any text containing numerals such as a ``Kalendarium``
+//! date, as markup which draws the bar itself: an overlined ``<span>`` in HTML, ``\overline`` in
+//! LaTeX and ``#overline`` in Typst. Framed numerals, e.g. |X̅| for 1,000,000, keep their frame.
+//! Everything else is escaped for the markup language, so the result can be pasted into a
+//! document as it is.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+#[cfg(feature = "std")]
+use crate::{Kalendarium, OutOfRangeError, Result};
+use crate::{NumeralStyle, Overbar, RomanNumeral};
+use core::fmt::{self, Write};
+
+/// A markup language in which the vinculum is drawn
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Markup {
+    /// HTML with inline CSS, e.g. ``<span class="vinculum" style="text-decoration: overline">V</span>``
+    /// for V̅. Each further bar is a border above the first.
+    Html,
+    /// LaTeX, e.g. ``\(\overline{\mbox{V}}\)`` for V̅
+    Latex,
+    /// Typst, e.g. ``#overline[V]`` for V̅
+    Typst,
+}
+
+/// The combining overline of the vinculum
+const OVERLINE: char = '\u{305}';
+/// The combining double overline of the double vinculum
+const DOUBLE_OVERLINE: char = '\u{33f}';
+
+impl Markup {
+    /// Converts ``text`` to this markup, drawing the bars over every barred letter.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    assert_eq!("#overline[IV]DC", Markup::Typst.render("I̅V̅DC"));
+    ///    assert_eq!("\\(\\left|\\overline{\\mbox{X}}\\right|\\)", Markup::Latex.render("|X̅|"));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn render(self, text: &str) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_text(text, &mut out);
+        out
+    }
+
+    /// Writes ``text`` in this markup to any ``fmt::Write``, without allocating.
+    ///
+    /// A pair of vertical bars is read as a frame only if every letter between them is barred.
+    pub fn write_text<W: Write>(self, text: &str, out: &mut W) -> fmt::Result {
+        let mut drawer = Drawer::new(self, out);
+        let mut rest = text;
+        while let Some(character) = rest.chars().next() {
+            if character == '|' {
+                if let Some(end) = rest[1..].find('|') {
+                    let inside = &rest[1..=end];
+                    if Self::is_barred(inside) {
+                        drawer.open_frame()?;
+                        inside.chars().try_for_each(|c| drawer.push(c))?;
+                        drawer.close_frame()?;
+                        rest = &rest[end + 2..];
+                        continue;
+                    }
+                }
+            }
+            drawer.push(character)?;
+            rest = &rest[character.len_utf8()..];
+        }
+        drawer.finish()
+    }
+
+    /// Helper function for whether ``text`` is made only of letters which each carry a bar
+    fn is_barred(text: &str) -> bool {
+        let mut chars = text.chars().peekable();
+        let mut any = false;
+        while let Some(letter) = chars.next() {
+            if Self::is_bar(letter) || chars.next_if(|&c| Self::is_bar(c)).is_none() {
+                return false;
+            }
+            while chars.next_if(|&c| Self::is_bar(c)).is_some() {}
+            any = true;
+        }
+        any
+    }
+
+    /// Helper function for whether a character is the combining mark of a vinculum
+    const fn is_bar(character: char) -> bool {
+        matches!(character, OVERLINE | DOUBLE_OVERLINE)
+    }
+
+    /// Helper function to write a character which is not a combining mark, escaped for the
+    /// markup
+    fn write_escaped<W: Write>(self, character: char, out: &mut W) -> fmt::Result {
+        match (self, character) {
+            (Markup::Html, '&') => out.write_str("&amp;"),
+            (Markup::Html, '<') => out.write_str("&lt;"),
+            (Markup::Html, '>') => out.write_str("&gt;"),
+            (Markup::Latex, '\\') => out.write_str("\\textbackslash{}"),
+            (Markup::Latex, '~') => out.write_str("\\textasciitilde{}"),
+            (Markup::Latex, '^') => out.write_str("\\textasciicircum{}"),
+            // A bar outside a frame prints as an em dash in the default OT1 encoding
+            (Markup::Latex, '|') => out.write_str("\\textbar{}"),
+            (Markup::Latex, '&' | '%' | '$' | '#' | '_' | '{' | '}')
+            | (
+                Markup::Typst,
+                '\\' | '#' | '$' | '*' | '_' | '`' | '<' | '>' | '@' | '[' | ']' | '~',
+            ) => {
+                out.write_char('\\')?;
+                out.write_char(character)
+            }
+            _ => out.write_char(character),
+        }
+    }
+}
+
+/// Helper writer which draws the bars of the letters written to it in markup
+///
+/// A letter is held back until the next character shows whether it carries any bars, and
+/// consecutive letters with the same number of bars share one piece of markup.
+struct Drawer<'w, W: Write> {
+    markup: Markup,
+    out: &'w mut W,
+    letter: Option<(char, u8)>,
+    bars: u8,
+    framed: bool,
+    called: bool,
+}
+
+impl<'w, W: Write> Drawer<'w, W> {
+    /// Helper function to draw in ``markup`` to ``out``
+    fn new(markup: Markup, out: &'w mut W) -> Self {
+        Drawer {
+            markup,
+            out,
+            letter: None,
+            bars: 0,
+            framed: false,
+            called: false,
+        }
+    }
+
+    /// Helper function to take the next character of the text
+    fn push(&mut self, character: char) -> fmt::Result {
+        // A mark with no letter to carry it is written as it is
+        match (character, &mut self.letter) {
+            (OVERLINE, Some((letter, bars))) if Self::carries_bars(*letter) => *bars += 1,
+            (DOUBLE_OVERLINE, Some((letter, bars))) if Self::carries_bars(*letter) => *bars += 2,
+            _ => {
+                self.settle()?;
+                self.letter = Some((character, 0));
+            }
+        }
+        Ok(())
+    }
+
+    /// Helper function for whether a character can carry the bars of a vinculum
+    fn carries_bars(character: char) -> bool {
+        !character.is_whitespace() && !Markup::is_bar(character)
+    }
+
+    /// Helper function to write the held back letter, opening or closing bars as needed
+    fn settle(&mut self) -> fmt::Result {
+        let Some((letter, bars)) = self.letter.take() else {
+            return Ok(());
+        };
+        if bars != self.bars {
+            self.close_bars()?;
+            self.open_bars(bars)?;
+        }
+        // In Typst a parenthesis, bracket or period right after #overline[...] would continue
+        // the call, so the call is ended with a semicolon
+        if self.called && matches!(letter, '(' | '[' | '.') {
+            self.out.write_char(';')?;
+        }
+        self.called = false;
+        self.markup.write_escaped(letter, self.out)
+    }
+
+    /// Helper function to open ``bars`` bars over the letters which follow
+    fn open_bars(&mut self, bars: u8) -> fmt::Result {
+        self.bars = bars;
+        if bars == 0 {
+            return Ok(());
+        }
+        if self.markup == Markup::Latex && !self.framed {
+            self.out.write_str("\\(")?;
+        }
+        for level in (1..=bars).rev() {
+            match (self.markup, level) {
+                (Markup::Html, 1) => self
+                    .out
+                    .write_str("<span class=\"vinculum\" style=\"text-decoration: overline\">")?,
+                (Markup::Html, _) => self.out.write_str(
+                    "<span class=\"vinculum\" style=\"border-top: 1px solid; padding-top: 1px\">",
+                )?,
+                (Markup::Latex, _) => self.out.write_str("\\overline{")?,
+                (Markup::Typst, _) => self.out.write_str("#overline[")?,
+            }
+        }
+        if self.markup == Markup::Latex {
+            self.out.write_str("\\mbox{")?;
+        }
+        Ok(())
+    }
+
+    /// Helper function to close the bars over the letters written so far
+    fn close_bars(&mut self) -> fmt::Result {
+        if self.bars == 0 {
+            return Ok(());
+        }
+        if self.markup == Markup::Latex {
+            self.out.write_char('}')?;
+        }
+        for _ in 0..self.bars {
+            match self.markup {
+                Markup::Html => self.out.write_str("</span>")?,
+                Markup::Latex => self.out.write_char('}')?,
+                Markup::Typst => self.out.write_char(']')?,
+            }
+        }
+        if self.markup == Markup::Latex && !self.framed {
+            self.out.write_str("\\)")?;
+        }
+        self.called = self.markup == Markup::Typst;
+        self.bars = 0;
+        Ok(())
+    }
+
+    /// Helper function to open the frame of a framed numeral, e.g. |X̅| for 1,000,000
+    fn open_frame(&mut self) -> fmt::Result {
+        self.settle()?;
+        self.close_bars()?;
+        self.framed = true;
+        self.called = false;
+        match self.markup {
+            Markup::Html => self.out.write_str(
+                "<span class=\"framed\" style=\"border-left: 1px solid; border-right: 1px solid\">",
+            ),
+            Markup::Latex => self.out.write_str("\\(\\left|"),
+            Markup::Typst => self.out.write_char('|'),
+        }
+    }
+
+    /// Helper function to close the frame of a framed numeral
+    fn close_frame(&mut self) -> fmt::Result {
+        self.settle()?;
+        self.close_bars()?;
+        self.framed = false;
+        self.called = false;
+        match self.markup {
+            Markup::Html => self.out.write_str("</span>"),
+            Markup::Latex => self.out.write_str("\\right|\\)"),
+            Markup::Typst => self.out.write_char('|'),
+        }
+    }
+
+    /// Helper function to write out everything held back
+    fn finish(&mut self) -> fmt::Result {
+        self.settle()?;
+        self.close_bars()
+    }
+}
+
+/// Helper writer which draws a numeral in markup as it is written, where every vertical bar is
+/// the edge of a frame
+struct NumeralDrawer<'w, W: Write>(Drawer<'w, W>);
+
+impl<W: Write> Write for NumeralDrawer<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for character in s.chars() {
+            match character {
+                '|' if self.0.framed => self.0.close_frame()?,
+                '|' => self.0.open_frame()?,
+                _ => self.0.push(character)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RomanNumeral {
+    /// Converts a ``RomanNumeral`` written in ``style`` to ``markup``, drawing the vinculum with
+    /// the markup rather than with combining characters.
+    ///
+    /// Returns ``OutOfRangeError`` only for zero, when the style has ``Zero::Error``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: RomanNumeral = RomanNumeral::new(4600)?;
+    ///    assert_eq!(
+    ///        "<span class=\"vinculum\" style=\"text-decoration: overline\">IV</span>DC",
+    ///        num.format_markup(&NumeralStyle::UPPERCASE, Markup::Html)?,
+    ///    );
+    ///
+    #[cfg(feature = "std")]
+    pub fn format_markup(
+        &self,
+        style: &NumeralStyle,
+        markup: Markup,
+    ) -> Result<String, OutOfRangeError> {
+        let mut out = String::new();
+        match self.write_markup(style, markup, &mut out) {
+            Ok(()) => Ok(out),
+            Err(_) => Err(OutOfRangeError),
+        }
+    }
+
+    /// Writes a ``RomanNumeral`` in ``style`` as ``markup`` to any ``fmt::Write``, without
+    /// allocating.
+    ///
+    /// The vinculum is always drawn by the markup, whatever the ``Overbar`` of the style.
+    pub fn write_markup<W: Write>(
+        &self,
+        style: &NumeralStyle,
+        markup: Markup,
+        out: &mut W,
+    ) -> fmt::Result {
+        let style = style.overbar(Overbar::Combining);
+        let mut drawer = NumeralDrawer(Drawer::new(markup, out));
+        self.write_with(&style, &mut drawer)?;
+        drawer.0.finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Kalendarium {
+    /// Displays a ``Kalendarium`` as an ancient Roman date string in ``markup``, drawing the
+    /// vinculum of large year numbers with the markup.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let kal: Kalendarium = Kalendarium::new("3300", "1", "1").unwrap();
+    ///    assert!(kal.to_markup(Markup::Typst).contains("#overline[IV]"));
+    ///
+    #[must_use]
+    pub fn to_markup(&self, markup: Markup) -> String {
+        markup.render(&self.to_str())
+    }
+}
@@ -1,35 +1,33 @@
 //! # kalendarium Roman numeral implementation
 //!
-//! A library for converting Arabic numerals to Classical Attic numerals
-//!
-//! TODO: implement Apostrophus (vs Vinculum) for numbers greater than 5000
-//!       https://en.wikipedia.org/wiki/Roman_numerals#Other_additive_forms
-//! TODO: implement Additive (vs Subtractive) for -4 and -9
-//!       e.g. XIIII and MDCCCC for 14 an 1900 respectively
+//! A library for converting Arabic numerals to Roman numerals
 //!
 //! ## License
 //!
 //! GNU GPL 3
 
-#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![warn(clippy::std_instead_of_core)]
 #![warn(clippy::print_stderr)]
 #![warn(clippy::print_stdout)]
 
-use crate::{OutOfRangeError, Result};
+use crate::{
+    Case, LargeNumerals, Letters, MAX, Manuscript, NumeralStyle, OutOfRangeError, ParseErrorKind,
+    ParseNumeralError, Result,
+};
 use core::fmt;
+use core::str::FromStr;
 
 /// A Roman numeral
 ///
-/// Values from 0 to 4,999,9999 are supported
+/// Values from 0 to 4,999,999,999 are supported
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct RomanNumeral(pub u32);
+pub struct RomanNumeral(pub u64);
 
 impl RomanNumeral {
     /// Creates a ``RomanNumeral`` for any value in range.
-    /// Requires ``value`` to be less than 5,000,000. 0 (ZERO) is acceptable.
+    /// Requires ``value`` to be less than 5,000,000,000. 0 (ZERO) is acceptable.
     ///
     /// Example
     /// -------
@@ -39,17 +37,35 @@ impl RomanNumeral {
     ///    let answer: RomanNumeral = RomanNumeral::new(42).unwrap();
     ///    assert_eq!("XLII", answer.to_uppercase());
     ///
-    pub const fn new(value: u32) -> Result<Self, OutOfRangeError> {
-        if value <= 4_999_999 {
-            // SAFETY: 0 <= value <= 4,999,999
+    pub const fn new(value: u64) -> Result<Self, OutOfRangeError> {
+        if value <= MAX {
+            // SAFETY: 0 <= value <= 4,999,999,999
             Ok(RomanNumeral(value))
         } else {
             Err(OutOfRangeError)
         }
     }
 
+    /// Return the value of this ``RomanNumeral`` as a ``u64``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: RomanNumeral = RomanNumeral::new(42)?;
+    ///    assert_eq!(answer.as_u64(), 42_u64);
+    ///
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
     /// Return the value of this ``RomanNumeral`` as a ``u32``.
     ///
+    /// Values larger than ``u32::MAX``, which only the double vinculum reaches, saturate at
+    /// ``u32::MAX``; use ``as_u64`` for the full range.
+    ///
     /// Example
     /// -------
     ///
@@ -60,7 +76,11 @@ impl RomanNumeral {
     ///
     #[must_use]
     pub const fn as_u32(self) -> u32 {
-        self.0
+        if self.0 <= u32::MAX as u64 {
+            self.0 as u32
+        } else {
+            u32::MAX
+        }
     }
 
     /// Converts a ``RomanNumeral`` to an uppercase string.
@@ -76,15 +96,10 @@ impl RomanNumeral {
     #[must_use]
     #[cfg(feature = "std")]
     pub fn to_uppercase(&self) -> String {
-        let mut out = String::new();
-        if self.0 == 0 {
-            out.push_str(&"N".to_string());
-        } else {
-            out.push_str(&Self::arabic_to_roman(self.0, true));
-        }
-        out
+        self.format_with(&NumeralStyle::UPPERCASE)
+            .unwrap_or_default()
     }
-    ///
+
     /// Converts a ``RomanNumeral`` to a lowercase string.
     ///
     /// Example
@@ -93,107 +108,241 @@ impl RomanNumeral {
     /// .. code-block:: rust
     ///
     ///    let answer: RomanNumeral = RomanNumeral::new(616)?;
-    ///    assert_eq!("dcxvi", answer.to_lowercase());
+    ///    assert_eq!("dcxui", answer.to_lowercase());
     ///
     #[must_use]
     #[cfg(feature = "std")]
     pub fn to_lowercase(self) -> String {
-        let mut out = String::new();
-        if self.0 == 0 {
-            out.push_str(&"N".to_string());
-        } else {
-            out.push_str(&Self::arabic_to_roman(self.0, false));
+        self.format_with(&NumeralStyle::LOWERCASE)
+            .unwrap_or_default()
+    }
+
+    /// Parses a ``RomanNumeral`` from a string.
+    ///
+    /// Only the canonical forms produced by ``to_uppercase`` and ``to_lowercase`` are accepted,
+    /// including the vinculum for numbers 4000 and larger, the double vinculum for 5,000,000 and
+    /// larger, and nulla (N) for zero. Four million may be written M̅M̅M̅M̅ or I̿V̿, as by
+    /// ``LargeNumerals::DoubleVinculum``. The framed notation written by ``LargeNumerals::Framed``,
+    /// e.g. ``|X̅|`` for 1,000,000, is accepted in its canonical form as well. Anything else, such
+    /// as ``IIII``, ``IC`` or mixed case, returns a ``ParseNumeralError``. Numerals written with the
+    /// Unicode Number Forms (``Letters::NumberForms``), e.g. ``ⅯⅯⅫ``, are accepted in the
+    /// canonical form of that style.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: RomanNumeral = RomanNumeral::parse("XLII")?;
+    ///    assert_eq!(answer.as_u64(), 42_u64);
+    ///
+    ///    let big: RomanNumeral = "C̅M̅X̅C̅I̅X̅CMXCIX".parse()?;
+    ///    assert_eq!(big.as_u64(), 999_999_u64);
+    ///
+    ///    let census: RomanNumeral = "|X̅|".parse()?;
+    ///    assert_eq!(census.as_u64(), 1_000_000_u64);
+    ///
+    pub fn parse(s: &str) -> Result<Self, ParseNumeralError> {
+        if s.is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        if s == "N" {
+            return Ok(RomanNumeral(0));
+        }
+
+        let (value, uppercase, framed) = Self::read_symbols(s)?;
+        match u64::try_from(value) {
+            Ok(number) if number <= MAX => {
+                let case = match uppercase {
+                    Some(false) => Case::Lower,
+                    _ => Case::Upper,
+                };
+                let large = if framed {
+                    LargeNumerals::Framed
+                } else {
+                    LargeNumerals::Vinculum
+                };
+                let letters = if s.chars().any(Self::is_number_form) {
+                    Letters::NumberForms
+                } else {
+                    Letters::Latin
+                };
+                let style = NumeralStyle::new().case(case).large(large).letters(letters);
+                let matched = |style: &NumeralStyle| {
+                    let mut canonical = Canonical::new(s);
+                    let result = RomanNumeral(number).write_with(style, &mut canonical);
+                    (result.is_ok() && canonical.is_complete(), canonical.matched)
+                };
+                // The millions may subtract with the double vinculum, as in I̿V̿ for M̅M̅M̅M̅
+                match (matched(&style), large) {
+                    ((true, _), _) => Ok(RomanNumeral(number)),
+                    ((false, _), LargeNumerals::Vinculum)
+                        if matched(&style.large(LargeNumerals::DoubleVinculum)).0 =>
+                    {
+                        Ok(RomanNumeral(number))
+                    }
+                    ((false, position), _) => Err(ParseNumeralError::new(
+                        ParseErrorKind::NonCanonical,
+                        position,
+                    )),
+                }
+            }
+            Ok(_) => Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0)),
+            Err(_) if value > 0 => Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, 0)),
+            Err(_) => Err(ParseNumeralError::new(ParseErrorKind::NonCanonical, 0)),
         }
-        out
     }
 
-    /// Helper function to repeat a character, needed for II, XX, CCC, etc.
-    fn repeat(total: u8, character: &str) -> String {
-        let mut count = total;
-        let mut out = String::new();
-        while count > 0 {
-            out.push_str(character);
-            count -= 1;
+    /// Parses a ``RomanNumeral`` written in ``style``.
+    ///
+    /// Accepted are the forms accepted by ``parse``, and the canonical form of any number written
+    /// in ``style``, such as ``IIII`` for 4 in an additive style, ``CIↃIↃ`` for 1500 with the
+    /// apostrophus, ``_V`` for 5000 with the ASCII vinculum, or ``nulla`` for zero with
+    /// ``Zero::Nulla``. A style with ``Manuscript`` conventions is read by ``parse_manuscript``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let clock = NumeralStyle::new().additive(AdditiveForms::CLOCK);
+    ///    assert_eq!(RomanNumeral::parse_with("IIII", &clock)?.as_u64(), 4_u64);
+    ///    assert_eq!(RomanNumeral::parse_with("XLII", &clock)?.as_u64(), 42_u64);
+    ///
+    pub fn parse_with(s: &str, style: &NumeralStyle) -> Result<Self, ParseNumeralError> {
+        let strict = Self::parse(s);
+        if strict.is_ok() {
+            return strict;
+        }
+        if style.manuscript_forms() != Manuscript::NONE {
+            return Self::parse_manuscript(s);
+        }
+        let read = Self::read_symbols(s)
+            .ok()
+            .and_then(|(value, _, _)| u64::try_from(value).ok());
+        // Zero is tried first, as nulla has no numeral letters to read
+        for number in [Some(0), read].into_iter().flatten() {
+            if number > MAX {
+                continue;
+            }
+            let mut canonical = Canonical::new(s);
+            let result = RomanNumeral(number).write_with(style, &mut canonical);
+            if result.is_ok() && canonical.is_complete() {
+                return Ok(RomanNumeral(number));
+            }
         }
-        out
+        strict
     }
 
-    /// Helper function to convert an Arabic numeral into a Roman one
-    ///
-    /// This looks complicated and I know there are more concise solutions that
-    /// I've seen in other libraries.
-    ///
-    /// I am using this one because I derived it during Covid lockdown in 2020-2021
-    /// without consulting any other solutions. What's fun about it now is that I
-    /// no longer am sure how exactly it works anymore, and I find beauty in knowing
-    /// that at one point my brain solved this problem in this way and now, though
-    /// I no longer intuit the solution, it remains a unique creation of my mind.
-    ///
-    /// In the original rust code, I painstakingly used Latin works for many of the terms.
-    /// Here, I have rendered then in English, though I have left the name of the struct
-    /// which contains all the conversion as its Latin name, NUMERI, the nominative plural
-    /// of the word for number.
-    fn arabic_to_roman(num: u32, uppercase: bool) -> String {
-        let mut out: String = String::new();
-        if num != 0 {
-            for (index, basis) in NUMERI.iter().enumerate() {
-                let arabic = num / basis.arabic; // Truncating division since both values are ints
-                if arabic > 0 {
-                    let offset = if basis.arabic.to_string().contains("5") {
-                        1
-                    } else {
-                        0
-                    };
-                    if num >= NUMERI[index - 1].arabic - NUMERI[index + offset].arabic {
-                        if 4000 <= num && num < 10000 {
-                            if uppercase {
-                                out.push_str(&"I̅".to_string());
-                            } else {
-                                out.push_str(&"i̅".to_string());
-                            }
-                        } else {
-                            if uppercase {
-                                out.push_str(NUMERI[index + offset].u_latin);
-                            } else {
-                                out.push_str(NUMERI[index + offset].l_latin);
-                            }
-                        }
-                        out.push_str(NUMERI[index - 1].u_latin);
-                        out.push_str(&Self::arabic_to_roman(
-                            (num - (NUMERI[index - 1].arabic - NUMERI[index + offset].arabic))
-                                % basis.arabic,
-                            uppercase,
-                        ));
-                    } else {
-                        if uppercase {
-                            out.push_str(&Self::repeat(
-                                u8::try_from(arabic).unwrap(),
-                                basis.u_latin,
-                            ));
-                        } else {
-                            out.push_str(&Self::repeat(
-                                u8::try_from(arabic).unwrap(),
-                                basis.l_latin,
-                            ));
-                        }
-                        out.push_str(&Self::arabic_to_roman(
-                            u32::try_from(
-                                (isize::try_from(num).unwrap()
-                                    - isize::try_from(NUMERI[index - 1].arabic).unwrap())
-                                .rem_euclid(isize::try_from(basis.arabic).unwrap()),
-                            )
-                            .unwrap(),
-                            uppercase,
-                        ));
-                    }
-                    break;
+    /// Helper function to read the symbols of a numeral in any style, returning their value,
+    /// whether the first letter is uppercase, and whether the numeral is framed
+    ///
+    /// Only the characters are checked, not whether they are in canonical order.
+    fn read_symbols(s: &str) -> Result<(i64, Option<bool>, bool), ParseNumeralError> {
+        let mut value: i64 = 0;
+        let mut previous: i64 = 0;
+        let mut uppercase = None;
+        let mut framed = false;
+        let mut frame = false;
+        let mut underscores = 0;
+        let mut hundreds = 0;
+        let mut chars = s.char_indices().peekable();
+        while let Some((position, character)) = chars.next() {
+            match character {
+                FRAME => {
+                    framed = true;
+                    frame = !frame;
+                    continue;
+                }
+                '_' => {
+                    underscores += 1;
+                    continue;
                 }
+                _ => {}
+            }
+            let mut symbol = match Self::symbol_value(character) {
+                Some(symbol) => i64::from(symbol),
+                None => {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::InvalidCharacter,
+                        position,
+                    ));
+                }
+            };
+            if uppercase.is_none() && (character.is_uppercase() || character.is_lowercase()) {
+                uppercase = Some(character.is_uppercase());
+            }
+            let mut reversed = 0;
+            while symbol == 1
+                && chars
+                    .next_if(|&(_, c)| matches!(c, 'Ↄ' | 'ↄ' | ')'))
+                    .is_some()
+            {
+                reversed += 1;
             }
+            if reversed > 0 {
+                // An apostrophic symbol: IↃↃ is 5000, and with as many Cs before it, CCIↃↃ is
+                // 10,000, which takes back the Cs already counted
+                symbol = if hundreds >= reversed {
+                    value -= 100 * i64::from(reversed);
+                    10_i64.saturating_pow(reversed + 2)
+                } else {
+                    10_i64.saturating_pow(reversed + 1).saturating_mul(5)
+                };
+                value = value.saturating_add(symbol);
+                (previous, hundreds) = (symbol, 0);
+                continue;
+            }
+            let mut bars = core::mem::take(&mut underscores);
+            while let Some((_, bar)) = chars.next_if(|&(_, c)| c == VINCULUM || c == DOUBLE) {
+                bars += if bar == DOUBLE { 2 } else { 1 };
+            }
+            // Inside a frame the first bar belongs to the frame, which multiplies by 100,000
+            if frame && bars > 0 {
+                bars -= 1;
+                symbol *= 100_000;
+            }
+            symbol = symbol.saturating_mul(1000_i64.saturating_pow(bars));
+            hundreds = if symbol == 100 { hundreds + 1 } else { 0 };
+            // Symbols are summed, except for one written before a larger one (IV, XC, I̅X̅)
+            if previous < symbol {
+                value = value.saturating_sub(previous.saturating_mul(2));
+            }
+            value = value.saturating_add(symbol);
+            previous = symbol;
         }
-        out
+        Ok((value, uppercase, framed))
+    }
+
+    /// Helper function returning the value of a single Roman numeral letter, or of a character
+    /// from the Unicode Number Forms such as Ⅻ
+    pub(crate) fn symbol_value(character: char) -> Option<u32> {
+        match character {
+            'I' | 'i' => Some(1),
+            'V' | 'v' | 'u' => Some(5),
+            'X' | 'x' => Some(10),
+            'L' | 'l' | 'Ⅼ' | 'ⅼ' | 'ↆ' => Some(50),
+            'C' | 'c' | 'Ⅽ' | 'ⅽ' => Some(100),
+            'D' | 'd' | 'Ⅾ' | 'ⅾ' => Some(500),
+            'M' | 'm' | 'Ⅿ' | 'ⅿ' | 'ↀ' => Some(1000),
+            'ↁ' => Some(5000),
+            'ↂ' => Some(10_000),
+            'ↅ' => Some(6),
+            'ↇ' => Some(50_000),
+            'ↈ' => Some(100_000),
+            // Ⅰ to Ⅻ and ⅰ to ⅻ
+            '\u{2160}'..='\u{216b}' => Some(character as u32 - 0x215f),
+            '\u{2170}'..='\u{217b}' => Some(character as u32 - 0x216f),
+            _ => None,
+        }
+    }
+
+    /// Helper function for whether a character is a Roman numeral from the Unicode Number Forms
+    pub(crate) fn is_number_form(character: char) -> bool {
+        ('\u{2160}'..='\u{2188}').contains(&character)
     }
 }
-#[cfg(feature = "std")]
+
 impl fmt::Display for RomanNumeral {
     /// Converts a ``RomanNumeral`` to an uppercase string.
     ///
@@ -203,88 +352,75 @@ impl fmt::Display for RomanNumeral {
     /// .. code-block:: rust
     ///
     ///    let answer: RomanNumeral = RomanNumeral::new(42)?;
-    ///    assert_eq!("ΜΒ'", answer.to_string());
+    ///    assert_eq!("XLII", answer.to_string());
     ///
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(&self.to_uppercase())
+        self.write_with(&NumeralStyle::UPPERCASE, f)
     }
 }
 
-/// Struct for holding the conversion values
-struct Arabic2RomanStruct<'a> {
-    arabic: u32,
-    u_latin: &'a str,
-    l_latin: &'a str,
+impl FromStr for RomanNumeral {
+    type Err = ParseNumeralError;
+
+    /// Parses a ``RomanNumeral`` from its canonical uppercase or lowercase string.
+    ///
+    /// Returns ``RomanNumeral`` or ``ParseNumeralError``.
+    fn from_str(s: &str) -> Result<Self, ParseNumeralError> {
+        Self::parse(s)
+    }
+}
+
+/// The combining overline which places a vinculum over the preceding letter
+pub(crate) const VINCULUM: char = '\u{305}';
+
+/// The combining double overline which places a double vinculum over the preceding letter
+pub(crate) const DOUBLE: char = '\u{33f}';
+
+/// The side of the frame around numerals multiplied by 100,000
+const FRAME: char = '|';
+
+/// Helper writer which checks a string against the canonical form as it is written
+pub(crate) struct Canonical<'a> {
+    expected: &'a str,
+    pub(crate) matched: usize,
+    normalise: fn(char) -> char,
+}
+
+impl<'a> Canonical<'a> {
+    /// Helper function to check ``expected`` exactly as it is written
+    pub(crate) const fn new(expected: &'a str) -> Self {
+        Canonical::normalised(expected, |character| character)
+    }
+
+    /// Helper function to check ``expected``, reading each of its characters through
+    /// ``normalise``, e.g. an ASCII apostrophe as a geresh
+    pub(crate) const fn normalised(expected: &'a str, normalise: fn(char) -> char) -> Self {
+        Canonical {
+            expected,
+            matched: 0,
+            normalise,
+        }
+    }
+
+    /// Helper function for whether all of the expected string was written
+    pub(crate) const fn is_complete(&self) -> bool {
+        self.matched == self.expected.len()
+    }
 }
 
-/// Array of conversions for arabic numerals to Latin numerals
-static NUMERI: [Arabic2RomanStruct; 13] = [
-    Arabic2RomanStruct {
-        arabic: 1000000,
-        u_latin: "M̅",
-        l_latin: "m̅",
-    },
-    Arabic2RomanStruct {
-        arabic: 500000,
-        u_latin: "D̅",
-        l_latin: "d̅",
-    },
-    Arabic2RomanStruct {
-        arabic: 100000,
-        u_latin: "C̅",
-        l_latin: "c̅",
-    },
-    Arabic2RomanStruct {
-        arabic: 50000,
-        u_latin: "L̅",
-        l_latin: "l̅",
-    },
-    Arabic2RomanStruct {
-        arabic: 10000,
-        u_latin: "X̅",
-        l_latin: "x̅",
-    },
-    Arabic2RomanStruct {
-        arabic: 5000,
-        u_latin: "V̅",
-        l_latin: "u̅",
-    },
-    Arabic2RomanStruct {
-        arabic: 1000,
-        u_latin: "M",
-        l_latin: "m",
-    },
-    Arabic2RomanStruct {
-        arabic: 500,
-        u_latin: "D",
-        l_latin: "d",
-    },
-    Arabic2RomanStruct {
-        arabic: 100,
-        u_latin: "C",
-        l_latin: "c",
-    },
-    Arabic2RomanStruct {
-        arabic: 50,
-        u_latin: "L",
-        l_latin: "l",
-    },
-    Arabic2RomanStruct {
-        arabic: 10,
-        u_latin: "X",
-        l_latin: "x",
-    },
-    Arabic2RomanStruct {
-        arabic: 5,
-        u_latin: "V",
-        l_latin: "u",
-    },
-    Arabic2RomanStruct {
-        arabic: 1,
-        u_latin: "I",
-        l_latin: "i",
-    },
-];
+impl fmt::Write for Canonical<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for character in s.chars() {
+            match self.expected[self.matched..].chars().next() {
+                Some(expected) if (self.normalise)(expected) == character => {
+                    self.matched += expected.len_utf8();
+                }
+                _ => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
 
 impl TryFrom<u8> for RomanNumeral {
     type Error = OutOfRangeError;
@@ -293,7 +429,7 @@ impl TryFrom<u8> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: u8) -> Result<Self, OutOfRangeError> {
-        Self::new(u32::from(value))
+        Self::new(u64::from(value))
     }
 }
 
@@ -304,7 +440,7 @@ impl TryFrom<u16> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
-        Self::new(u32::from(value))
+        Self::new(u64::from(value))
     }
 }
 
@@ -315,7 +451,7 @@ impl TryFrom<u32> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
-        Self::new(value)
+        Self::new(u64::from(value))
     }
 }
 
@@ -326,7 +462,7 @@ impl TryFrom<u64> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        Self::new(value)
     }
 }
 
@@ -337,7 +473,7 @@ impl TryFrom<u128> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u64::try_from(value).map_or(Err(OutOfRangeError), Self::new)
     }
 }
 
@@ -348,7 +484,7 @@ impl TryFrom<usize> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u64::try_from(value).map_or(Err(OutOfRangeError), Self::new)
     }
 }
 
@@ -359,7 +495,7 @@ impl TryFrom<i8> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i8) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u64::try_from(value).map_or(Err(OutOfRangeError), Self::new)
     }
 }
 
@@ -370,7 +506,7 @@ impl TryFrom<i16> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i16) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u64::try_from(value).map_or(Err(OutOfRangeError), Self::new)
     }
 }
 
@@ -381,7 +517,7 @@ impl TryFrom<i32> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i32) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u64::try_from(value).map_or(Err(OutOfRangeError), Self::new)
     }
 }
 
@@ -392,7 +528,7 @@ impl TryFrom<i64> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u64::try_from(value).map_or(Err(OutOfRangeError), Self::new)
     }
 }
 
@@ -403,6 +539,6 @@ impl TryFrom<i128> for RomanNumeral {
     ///
     /// Returns ``RomanNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u64::try_from(value).map_or(Err(OutOfRangeError), Self::new)
     }
 }
@@ -0,0 +1,298 @@
+//! # kalendarium Roman numerals in running text
+//!
+//! Book indices, tables of contents and old prose mix Roman numerals with ordinary words, and many
+//! words are made of numeral letters too: the pronoun I, mix, or DC for the capital. A
+//! ``NumeralScanner`` finds the numerals in a text and returns their byte spans and values. A word
+//! is read as a numeral only if it is written entirely in numeral letters of one case and in
+//! canonical form, so civil or Mild are never read. The bars and frames of large numerals belong
+//! to the word, so I̅V̅DC is read whole as 4600. The words of an exclusion list are only read
+//! after a heading keyword such as Chapter, or before a period when the ``ScanContext`` asks for
+//! one. A ``ScanContext`` can further require every numeral to follow a heading keyword, or to be
+//! followed by a period.
+//!
+//! The scanner can also rewrite a text in either direction, from Roman numerals to Arabic ones or
+//! the other way around.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{NumeralStyle, RomanNumeral};
+use core::ops::Range;
+
+/// Where in a text a numeral must stand to be read
+///
+/// With neither required, every numeral is read. With one or both required, a numeral must
+/// stand in at least one of the required places.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct ScanContext {
+    heading: bool,
+    period: bool,
+}
+
+impl ScanContext {
+    /// Numerals are read wherever they stand
+    pub const ANY: ScanContext = ScanContext {
+        heading: false,
+        period: false,
+    };
+
+    /// Sets whether a numeral after a heading keyword is read, e.g. Chapter XII.
+    #[must_use]
+    pub const fn heading(self, heading: bool) -> Self {
+        ScanContext { heading, ..self }
+    }
+
+    /// Sets whether a numeral followed by a period is read, e.g. XII. in a table of contents.
+    #[must_use]
+    pub const fn period(self, period: bool) -> Self {
+        ScanContext { period, ..self }
+    }
+}
+
+/// A numeral found in a text by a ``NumeralScanner``
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NumeralMatch {
+    /// The value of the numeral
+    pub value: RomanNumeral,
+    /// The byte range of the text where the numeral was found
+    pub span: Range<usize>,
+}
+
+/// Finds Roman numerals in running text, and rewrites them
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let scanner = NumeralScanner::new();
+///    let found: Vec<NumeralMatch> = scanner.scan("I read Chapter XII in DC.");
+///    assert_eq!(found.len(), 1);
+///    assert_eq!(found[0].value.as_u64(), 12);
+///    assert_eq!(found[0].span, 15..18);
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct NumeralScanner<'a> {
+    lowercase: bool,
+    context: ScanContext,
+    excluded: &'a [&'a str],
+    keywords: &'a [&'a str],
+}
+
+impl<'a> NumeralScanner<'a> {
+    /// Words made of numeral letters which are more likely to be words or abbreviations of units,
+    /// e.g. 12 cm, only read as numerals in a heading or before a required period
+    pub const EXCLUDED_WORDS: &'static [&'static str] = &[
+        "I", "MIX", "DC", "CD", "MD", "DI", "LI", "MI", "DIV", "MM", "CM", "ML", "CL", "DL", "CC",
+    ];
+
+    /// Words after which a numeral is taken to be a heading, e.g. Chapter XII
+    pub const HEADING_KEYWORDS: &'static [&'static str] = &[
+        "chapter", "book", "part", "volume", "vol.", "section", "act", "scene", "canto", "psalm",
+        "appendix", "page", "p.", "pp.",
+    ];
+
+    /// Creates the default scanner: uppercase numerals anywhere, except the ``EXCLUDED_WORDS``
+    /// unless they follow one of the ``HEADING_KEYWORDS``.
+    #[must_use]
+    pub const fn new() -> Self {
+        NumeralScanner {
+            lowercase: false,
+            context: ScanContext::ANY,
+            excluded: Self::EXCLUDED_WORDS,
+            keywords: Self::HEADING_KEYWORDS,
+        }
+    }
+
+    /// Sets whether lowercase numerals are read as well, e.g. the xiv of a preface page.
+    #[must_use]
+    pub const fn lowercase(self, lowercase: bool) -> Self {
+        NumeralScanner { lowercase, ..self }
+    }
+
+    /// Sets where in the text a numeral must stand to be read.
+    #[must_use]
+    pub const fn context(self, context: ScanContext) -> Self {
+        NumeralScanner { context, ..self }
+    }
+
+    /// Sets the words which are only read as numerals after a heading keyword. They are compared
+    /// ignoring case.
+    #[must_use]
+    pub const fn excluded(self, excluded: &'a [&'a str]) -> Self {
+        NumeralScanner { excluded, ..self }
+    }
+
+    /// Sets the heading keywords. They are compared with the word before a numeral, ignoring
+    /// case.
+    #[must_use]
+    pub const fn keywords(self, keywords: &'a [&'a str]) -> Self {
+        NumeralScanner { keywords, ..self }
+    }
+
+    /// Finds every Roman numeral in ``text``, in order.
+    pub fn scan(&self, text: &str) -> Vec<NumeralMatch> {
+        Self::words(text)
+            .filter_map(|span| {
+                let word = &text[span.clone()];
+                let letters = if self.lowercase {
+                    "IVXLCDMivxlcdm"
+                } else {
+                    "IVXLCDM"
+                };
+                // The bars of the vinculum and the frame of a framed numeral are not letters
+                let symbols = || word.chars().filter(|&c| !Self::is_mark(c));
+                let one_case = symbols().all(|c| c.is_ascii_uppercase())
+                    || symbols().all(|c| c.is_ascii_lowercase());
+                if !one_case || !symbols().all(|c| letters.contains(c)) {
+                    return None;
+                }
+                let heading = self.is_heading(text, &span);
+                let period = self.context.period && text[span.end..].starts_with('.');
+                if !heading
+                    && !period
+                    && self
+                        .excluded
+                        .iter()
+                        .any(|excluded| excluded.eq_ignore_ascii_case(word))
+                {
+                    return None;
+                }
+                if !self.in_context(text, &span, heading) {
+                    return None;
+                }
+                // Lowercase numerals in print write five with v, not the u of to_lowercase
+                let value = RomanNumeral::parse(&word.to_ascii_uppercase()).ok()?;
+                Some(NumeralMatch { value, span })
+            })
+            .collect()
+    }
+
+    /// Rewrites ``text`` with every Roman numeral replaced by its Arabic value.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let scanner = NumeralScanner::new();
+    ///    assert_eq!("Book 3, Chapter 1", scanner.to_arabic("Book III, Chapter I"));
+    ///
+    pub fn to_arabic(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut end = 0;
+        for found in self.scan(text) {
+            out.push_str(&text[end..found.span.start]);
+            out.push_str(&found.value.as_u64().to_string());
+            end = found.span.end;
+        }
+        out.push_str(&text[end..]);
+        out
+    }
+
+    /// Rewrites ``text`` with every Arabic number written as a Roman numeral in ``style``.
+    ///
+    /// Numbers are whole words of ASCII digits, standing where the ``ScanContext`` allows. Parts
+    /// of a decimal or grouped number such as 3.14 or 1,000, numbers with a leading zero, and
+    /// numbers which cannot be written in ``style`` are left as they are.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let scanner = NumeralScanner::new();
+    ///    let text = scanner.to_roman("Chapter 12, pp. 4-9", &NumeralStyle::UPPERCASE);
+    ///    assert_eq!("Chapter XII, pp. IV-IX", text);
+    ///
+    pub fn to_roman(&self, text: &str, style: &NumeralStyle) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut end = 0;
+        for span in Self::words(text) {
+            let word = &text[span.clone()];
+            if !word.bytes().all(|b| b.is_ascii_digit())
+                || (word.len() > 1 && word.starts_with('0'))
+                || Self::is_grouped(text, &span)
+                || !self.in_context(text, &span, self.is_heading(text, &span))
+            {
+                continue;
+            }
+            let Some(roman) = word
+                .parse::<u64>()
+                .ok()
+                .and_then(|value| RomanNumeral::new(value).ok())
+                .and_then(|num| num.format_with(style).ok())
+            else {
+                continue;
+            };
+            out.push_str(&text[end..span.start]);
+            out.push_str(&roman);
+            end = span.end;
+        }
+        out.push_str(&text[end..]);
+        out
+    }
+
+    /// Helper function for the byte spans of the words of ``text``: runs of letters, digits,
+    /// apostrophes and underscores, so that I'm is not read as I and M, together with the bars
+    /// and frames of large numerals, so that I̅V̅DC is one word
+    pub(crate) fn words(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+        let is_word =
+            |c: char| c.is_alphanumeric() || Self::is_mark(c) || matches!(c, '\'' | '’' | '_');
+        let mut chars = text.char_indices().peekable();
+        core::iter::from_fn(move || {
+            let (start, _) = chars.find(|&(_, c)| is_word(c))?;
+            let mut end = text.len();
+            while let Some(&(position, c)) = chars.peek() {
+                if !is_word(c) {
+                    end = position;
+                    break;
+                }
+                chars.next();
+            }
+            Some(start..end)
+        })
+    }
+
+    /// Helper function for whether a character is a bar of the vinculum or the frame of a framed
+    /// numeral
+    const fn is_mark(character: char) -> bool {
+        matches!(character, '\u{305}' | '\u{33f}' | '|')
+    }
+
+    /// Helper function for whether the word before ``span`` is a heading keyword
+    fn is_heading(&self, text: &str, span: &Range<usize>) -> bool {
+        text[..span.start]
+            .split_whitespace()
+            .next_back()
+            .is_some_and(|before| {
+                self.keywords
+                    .iter()
+                    .any(|keyword| keyword.eq_ignore_ascii_case(before))
+            })
+    }
+
+    /// Helper function for whether a word stands where the ``ScanContext`` allows
+    fn in_context(&self, text: &str, span: &Range<usize>, heading: bool) -> bool {
+        let context = self.context;
+        if !context.heading && !context.period {
+            return true;
+        }
+        (context.heading && heading) || (context.period && text[span.end..].starts_with('.'))
+    }
+
+    /// Helper function for whether a run of digits is part of a decimal or grouped number
+    fn is_grouped(text: &str, span: &Range<usize>) -> bool {
+        let before = &text.as_bytes()[..span.start];
+        let after = &text.as_bytes()[span.end..];
+        matches!(before, [.., b'0'..=b'9', b'.' | b','])
+            || matches!(after, [b'.' | b',', b'0'..=b'9', ..])
+    }
+}
+
+impl Default for NumeralScanner<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,102 @@
+//! # kalendarium natural sort by Roman numerals
+//!
+//! Names of rulers and popes carry regnal numerals, which plain text comparison puts in the wrong
+//! order: Henry IX before Henry VIII, and Louis XIX before Louis XVIII. It puts Louis XIV before
+//! Louis XVIII only by luck, because I comes before V. A ``NumeralScanner`` splits a string into
+//! text and the numerals it finds, Roman or Arabic, and compares the numerals by value, so that
+//! Henry VIII < Henry IX < Henry X. The scanner's settings are the policy for which words count as
+//! numerals.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::NumeralScanner;
+use core::cmp::Ordering;
+
+/// A key which sorts strings by the values of their numerals, from ``NumeralScanner::sort_key``
+///
+/// Keys compare part by part: numerals by value, and the text between them as plain strings, with
+/// a numeral before any text. Strings whose parts are equal, such as Louis XIV and Louis 14, are
+/// ordered as plain strings.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SortKey {
+    parts: Vec<SortPart>,
+    text: String,
+}
+
+/// Helper type for one part of a ``SortKey``
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum SortPart {
+    Numeral(u64),
+    Text(String),
+}
+
+impl NumeralScanner<'_> {
+    /// A scanner for the names of rulers, which reads every numeral, including I, e.g. Charles I
+    pub const REGNAL: NumeralScanner<'static> = NumeralScanner::new().excluded(&[]);
+
+    /// Returns a key which sorts ``s`` by the values of its numerals.
+    ///
+    /// Roman numerals are found with ``scan``, and Arabic numbers are whole words of ASCII
+    /// digits.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let mut kings = vec!["Henry X", "Henry VIII", "Henry IX"];
+    ///    kings.sort_by_cached_key(|name| NumeralScanner::REGNAL.sort_key(name));
+    ///    assert_eq!(kings, ["Henry VIII", "Henry IX", "Henry X"]);
+    ///
+    pub fn sort_key(&self, s: &str) -> SortKey {
+        let mut numerals: Vec<_> = self
+            .scan(s)
+            .into_iter()
+            .map(|found| (found.span, found.value.as_u64()))
+            .collect();
+        numerals.extend(Self::words(s).filter_map(|span| {
+            let word = &s[span.clone()];
+            if !word.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let value = word.parse::<u64>().ok()?;
+            Some((span, value))
+        }));
+        numerals.sort_by_key(|(span, _)| span.start);
+
+        let mut parts = Vec::new();
+        let mut end = 0;
+        for (span, value) in numerals {
+            if span.start > end {
+                parts.push(SortPart::Text(String::from(&s[end..span.start])));
+            }
+            parts.push(SortPart::Numeral(value));
+            end = span.end;
+        }
+        if end < s.len() {
+            parts.push(SortPart::Text(String::from(&s[end..])));
+        }
+        SortKey {
+            parts,
+            text: String::from(s),
+        }
+    }
+
+    /// Compares two strings by the values of their numerals, as their ``sort_key`` would.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let scanner = NumeralScanner::REGNAL;
+    ///    assert_eq!(Ordering::Less, scanner.compare("Louis XIV", "Louis XVIII"));
+    ///    assert_eq!(Ordering::Less, scanner.compare("Charles I", "Charles II"));
+    ///
+    #[must_use]
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.sort_key(a).cmp(&self.sort_key(b))
+    }
+}
@@ -0,0 +1,545 @@
+//! # kalendarium numeral styles
+//!
+//! Everything about how a ``RomanNumeral`` is written out is gathered in a ``NumeralStyle``: the
+//! case, which fours and nines are subtracted, how numbers of a thousand and more are written,
+//! what zero looks like, whether five is a ``u`` or a ``v``, how the vinculum is drawn, and which
+//! medieval manuscript conventions are kept. A style is a small ``Copy`` value built with
+//! ``const`` methods, so it can be declared once and shared.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+#[cfg(feature = "std")]
+use crate::OutOfRangeError;
+use crate::{AdditiveForms, Apostrophus, Manuscript, Result, RomanNumeral};
+use core::fmt::{self, Write};
+
+/// The letter case of a numeral
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Case {
+    /// Uppercase letters, e.g. MCMLXXXIV
+    #[default]
+    Upper,
+    /// Lowercase letters, e.g. mcmlxxxiv
+    Lower,
+}
+
+/// How numbers of a thousand and more are written
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub enum LargeNumerals {
+    /// A bar over the letters multiplies them by 1000, e.g. V̅ for 5000, and a double bar by a
+    /// million, e.g. V̿ for 5,000,000. The millions are written additively with M̅, e.g. M̅M̅M̅M̅ for
+    /// 4,000,000.
+    #[default]
+    Vinculum,
+    /// As ``Vinculum``, but the millions subtract with the double vinculum as well, e.g. I̿V̿ for
+    /// 4,000,000
+    DoubleVinculum,
+    /// The apostrophus for 500 and up, e.g. CIↃIↃ for 1500
+    Apostrophus(Apostrophus),
+    /// The vinculum written in plain ASCII with an underscore before each barred letter, e.g.
+    /// _V for 5000
+    AsciiVinculum,
+    /// The hundreds of thousands written inside a frame, which multiplies them by 100,000, e.g.
+    /// |X̅| for 1,000,000 and |X̅I̅| for 1,100,000
+    Framed,
+}
+
+/// How zero is written
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Zero {
+    /// nulla abbreviated to N, always uppercase
+    #[default]
+    N,
+    /// The word nulla written out
+    Nulla,
+    /// Zero cannot be written, and formatting returns an ``OutOfRangeError``
+    Error,
+}
+
+/// The letter used for five
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum VLetter {
+    /// V when uppercase and u when lowercase, as ``to_uppercase`` and ``to_lowercase`` write it
+    #[default]
+    Customary,
+    /// Always V or v
+    V,
+    /// Always U or u
+    U,
+}
+
+/// How the bar of the vinculum is drawn
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Overbar {
+    /// The combining overline (U+0305) after each letter, which joins into a continuous bar
+    #[default]
+    Combining,
+    /// The precomposed letters Ī and Ū where Unicode has them, and the combining macron (U+0304)
+    /// elsewhere, for fonts which lack the overline
+    Precomposed,
+}
+
+/// The characters a numeral is written with
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Letters {
+    /// Latin letters, e.g. MMXII
+    #[default]
+    Latin,
+    /// The Roman numerals of the Unicode Number Forms block (U+2160–U+2188), e.g. ⅯⅯⅫ
+    ///
+    /// The single characters for one to twelve are used for the last two places where they
+    /// exist, and ↁ ↂ ↇ ↈ for 5000, 10,000, 50,000 and 100,000 in place of the vinculum. Five is
+    /// always Ⅴ, as the block has no U.
+    NumberForms,
+}
+
+/// A complete description of how to write out a ``RomanNumeral``
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    const INSCRIPTION: NumeralStyle = NumeralStyle::new().additive(AdditiveForms::ALL);
+///
+///    let year: RomanNumeral = RomanNumeral::new(1999)?;
+///    assert_eq!("MDCCCCLXXXXVIIII", year.format_with(&INSCRIPTION)?);
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct NumeralStyle {
+    case: Case,
+    additive: AdditiveForms,
+    large: LargeNumerals,
+    zero: Zero,
+    v_letter: VLetter,
+    overbar: Overbar,
+    letters: Letters,
+    manuscript: Manuscript,
+}
+
+impl NumeralStyle {
+    /// The style of ``to_uppercase``
+    pub const UPPERCASE: NumeralStyle = NumeralStyle::new();
+    /// The style of ``to_lowercase``
+    pub const LOWERCASE: NumeralStyle = NumeralStyle::new().case(Case::Lower);
+
+    /// Creates the default style: uppercase, subtractive, with the vinculum and N for zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        NumeralStyle {
+            case: Case::Upper,
+            additive: AdditiveForms::NONE,
+            large: LargeNumerals::Vinculum,
+            zero: Zero::N,
+            v_letter: VLetter::Customary,
+            overbar: Overbar::Combining,
+            letters: Letters::Latin,
+            manuscript: Manuscript::NONE,
+        }
+    }
+
+    /// Sets the letter case.
+    #[must_use]
+    pub const fn case(self, case: Case) -> Self {
+        NumeralStyle { case, ..self }
+    }
+
+    /// Sets which fours and nines are written additively.
+    #[must_use]
+    pub const fn additive(self, additive: AdditiveForms) -> Self {
+        NumeralStyle { additive, ..self }
+    }
+
+    /// Sets how numbers of a thousand and more are written.
+    #[must_use]
+    pub const fn large(self, large: LargeNumerals) -> Self {
+        NumeralStyle { large, ..self }
+    }
+
+    /// Sets how zero is written.
+    #[must_use]
+    pub const fn zero(self, zero: Zero) -> Self {
+        NumeralStyle { zero, ..self }
+    }
+
+    /// Sets the letter used for five.
+    #[must_use]
+    pub const fn v_letter(self, v_letter: VLetter) -> Self {
+        NumeralStyle { v_letter, ..self }
+    }
+
+    /// Sets how the bar of the vinculum is drawn.
+    #[must_use]
+    pub const fn overbar(self, overbar: Overbar) -> Self {
+        NumeralStyle { overbar, ..self }
+    }
+
+    /// Sets the characters the numeral is written with.
+    #[must_use]
+    pub const fn letters(self, letters: Letters) -> Self {
+        NumeralStyle { letters, ..self }
+    }
+
+    /// Sets which medieval manuscript conventions the numeral is written with.
+    #[must_use]
+    pub const fn manuscript(self, manuscript: Manuscript) -> Self {
+        NumeralStyle { manuscript, ..self }
+    }
+
+    /// Helper function to write a non-zero number, framing the hundreds of thousands if the style
+    /// asks for it
+    pub(crate) fn write<W: Write>(&self, num: u64, out: &mut W) -> fmt::Result {
+        if self.large == LargeNumerals::Framed && num >= FRAMED {
+            // The frame carries a bar of its own over the letters inside it
+            out.write_char('|')?;
+            self.write_places(num / FRAMED, 1, out)?;
+            out.write_char('|')?;
+            self.write_places(num % FRAMED, 0, out)
+        } else {
+            self.write_places(num, 0, out)
+        }
+    }
+
+    /// Helper function to write a number one decimal place at a time, adding ``bars`` to every
+    /// letter
+    ///
+    /// Every symbol comes from a table, so nothing is allocated and any ``fmt::Write`` will do.
+    fn write_places<W: Write>(&self, num: u64, bars: u8, out: &mut W) -> fmt::Result {
+        for (place, &(one, five, ten)) in PLACES.iter().enumerate().rev() {
+            let digit = (num / POWERS[place] % 10) as u32;
+            if digit == 0 {
+                continue;
+            }
+            if self.letters == Letters::NumberForms && bars == 0 {
+                // Ⅺ and Ⅻ take the units with them, and the units are always a single character
+                // unless a four or nine is written additively
+                let units = (num % 10) as u32;
+                if place == 1 && digit == 1 && (1..=2).contains(&units) {
+                    return out.write_char(self.number_form(10 + units));
+                }
+                if place == 0 && !(matches!(digit, 4 | 9) && self.additive.is_additive(0, digit)) {
+                    return out.write_char(self.number_form(digit));
+                }
+            }
+            if let LargeNumerals::Apostrophus(form) = self.large {
+                if place >= 2 {
+                    // A C written before an apostrophic symbol would read as part of it, so these
+                    // places are always additive
+                    if digit >= 5 {
+                        RomanNumeral::write_apostrophus(out, place, true, form, self)?;
+                    }
+                    for _ in 0..digit % 5 {
+                        RomanNumeral::write_apostrophus(out, place, false, form, self)?;
+                    }
+                    continue;
+                }
+            }
+
+            // Before the double vinculum was written, four million was M̅M̅M̅M̅
+            let millions = place == 6 && self.large != LargeNumerals::DoubleVinculum;
+            let subtractive = match (digit, five, ten) {
+                (4, Some(_), _) | (9, _, Some(_)) => {
+                    !millions && !self.additive.is_additive(place as u32, digit)
+                }
+                _ => false,
+            };
+            if let (true, Some(five), Some(ten)) = (subtractive, five, ten) {
+                // The thousands and millions subtract with I̅ and I̿ rather than M and M̅, as in
+                // I̅V̅ for 4000, except before ↁ and ↂ
+                if place == 3 && self.has_number_form_thousands() {
+                    self.write_glyph(out, one)?;
+                } else if place % 3 == 0 {
+                    self.write_glyph(out, Glyph('I', (place / 3) as u8).raised(bars))?;
+                } else {
+                    self.write_glyph(out, one.raised(bars))?;
+                }
+                let next = if digit == 4 { five } else { ten };
+                self.write_glyph(out, next.raised(bars))?;
+            } else {
+                let mut count = digit;
+                if let (true, Some(five)) = (digit >= 5, five) {
+                    self.write_glyph(out, five.raised(bars))?;
+                    count -= 5;
+                }
+                for _ in 0..count {
+                    self.write_glyph(out, one.raised(bars))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Helper function to write a single letter, with its vinculum if it has one
+    fn write_glyph<W: Write>(&self, out: &mut W, glyph: Glyph) -> fmt::Result {
+        let Glyph(mut letter, bars) = glyph;
+        if self.letters == Letters::NumberForms {
+            let thousands = match (letter, bars) {
+                ('V', 1) => Some('ↁ'),
+                ('X', 1) => Some('ↂ'),
+                ('L', 1) => Some('ↇ'),
+                ('C', 1) => Some('ↈ'),
+                _ => None,
+            };
+            if let (Some(symbol), true) = (thousands, self.has_number_form_thousands()) {
+                return out.write_char(symbol);
+            }
+            letter = match letter {
+                'I' => self.number_form(1),
+                'V' => self.number_form(5),
+                'X' => self.number_form(10),
+                'L' => self.number_form_letter('Ⅼ'),
+                'C' => self.number_form_letter('Ⅽ'),
+                'D' => self.number_form_letter('Ⅾ'),
+                _ => self.number_form_letter('Ⅿ'),
+            };
+        } else {
+            if letter == 'V'
+                && (self.v_letter == VLetter::U
+                    || (self.v_letter == VLetter::Customary && self.case == Case::Lower))
+            {
+                letter = 'U';
+            }
+            if self.case == Case::Lower {
+                letter = letter.to_ascii_lowercase();
+            }
+        }
+        if bars == 0 {
+            if self.manuscript.has_stroked_v() && matches!(letter, 'V' | 'U' | 'v' | 'u') {
+                letter = if self.is_upper() { 'Ꝟ' } else { 'ꝟ' };
+            }
+            out.write_char(letter)
+        } else if self.large == LargeNumerals::AsciiVinculum {
+            for _ in 0..bars {
+                out.write_char('_')?;
+            }
+            out.write_char(letter)
+        } else if bars > 1 {
+            // The double vinculum multiplies by a million, as in M̿ for 1,000,000,000
+            out.write_char(letter)?;
+            for _ in 0..bars / 2 {
+                out.write_char('\u{33f}')?;
+            }
+            if bars % 2 == 1 {
+                out.write_char('\u{305}')?;
+            }
+            Ok(())
+        } else if self.overbar == Overbar::Combining {
+            out.write_char(letter)?;
+            out.write_char('\u{305}')
+        } else {
+            match letter {
+                'I' => out.write_char('Ī'),
+                'i' => out.write_char('ī'),
+                'U' => out.write_char('Ū'),
+                'u' => out.write_char('ū'),
+                other => {
+                    out.write_char(other)?;
+                    out.write_char('\u{304}')
+                }
+            }
+        }
+    }
+
+    /// Helper function for whether letters are written uppercase
+    pub(crate) const fn is_upper(&self) -> bool {
+        matches!(self.case, Case::Upper)
+    }
+
+    /// Helper function for whether letters are written with the Unicode Number Forms
+    pub(crate) const fn is_number_forms(&self) -> bool {
+        matches!(self.letters, Letters::NumberForms)
+    }
+
+    /// Helper function returning the manuscript conventions of the style
+    pub(crate) const fn manuscript_forms(&self) -> Manuscript {
+        self.manuscript
+    }
+
+    /// Helper function for whether ↁ ↂ ↇ ↈ are written instead of the vinculum
+    const fn has_number_form_thousands(&self) -> bool {
+        self.is_number_forms()
+            && matches!(
+                self.large,
+                LargeNumerals::Vinculum | LargeNumerals::DoubleVinculum
+            )
+    }
+
+    /// Helper function returning the single character for one to twelve, e.g. Ⅻ
+    fn number_form(&self, value: u32) -> char {
+        let first = if self.is_upper() { 0x215f } else { 0x216f };
+        char::from_u32(first + value).unwrap_or('\u{fffd}')
+    }
+
+    /// Helper function returning an uppercase Number Forms letter in the case of the style
+    fn number_form_letter(&self, letter: char) -> char {
+        if self.is_upper() {
+            letter
+        } else {
+            char::from_u32(letter as u32 + 0x10).unwrap_or(letter)
+        }
+    }
+}
+
+impl Default for NumeralStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RomanNumeral {
+    /// Converts a ``RomanNumeral`` to a string written in ``style``.
+    ///
+    /// Returns ``OutOfRangeError`` only for zero, when the style has ``Zero::Error``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let style = NumeralStyle::new()
+    ///        .case(Case::Lower)
+    ///        .additive(AdditiveForms::CLOCK)
+    ///        .v_letter(VLetter::V);
+    ///
+    ///    let answer: RomanNumeral = RomanNumeral::new(1984)?;
+    ///    assert_eq!("mcmlxxxiiii", answer.format_with(&style)?);
+    ///
+    #[cfg(feature = "std")]
+    pub fn format_with(&self, style: &NumeralStyle) -> Result<String, OutOfRangeError> {
+        let mut out = String::new();
+        match self.write_with(style, &mut out) {
+            Ok(()) => Ok(out),
+            Err(_) => Err(OutOfRangeError),
+        }
+    }
+
+    /// Writes a ``RomanNumeral`` in ``style`` to any ``fmt::Write``, without allocating.
+    ///
+    /// Returns ``fmt::Error`` if ``out`` does, or for zero when the style has ``Zero::Error``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let mut out = String::new();
+    ///    RomanNumeral::new(616)?.write_with(&NumeralStyle::LOWERCASE, &mut out)?;
+    ///    assert_eq!("dcxui", out);
+    ///
+    pub fn write_with<W: Write>(&self, style: &NumeralStyle, out: &mut W) -> fmt::Result {
+        if self.0 != 0 && style.manuscript != Manuscript::NONE {
+            return style.write_manuscript(self.0, out);
+        }
+        if self.0 != 0 {
+            return style.write(self.0, out);
+        }
+        match style.zero {
+            Zero::N => out.write_char('N'),
+            Zero::Nulla => out.write_str("nulla"),
+            Zero::Error => Err(fmt::Error),
+        }
+    }
+
+    /// Writes a ``RomanNumeral`` in ``style`` into a caller-supplied buffer, returning the part of
+    /// the buffer written to.
+    ///
+    /// Returns ``fmt::Error`` if the buffer is too small, or for zero when the style has
+    /// ``Zero::Error``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let mut buf = [0_u8; 64];
+    ///    let roman: &str = RomanNumeral::new(1984)?.format_into(&NumeralStyle::UPPERCASE, &mut buf)?;
+    ///    assert_eq!("MCMLXXXIV", roman);
+    ///
+    pub fn format_into<'b>(
+        &self,
+        style: &NumeralStyle,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, fmt::Error> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        self.write_with(style, &mut writer)?;
+        let SliceWriter { buf, len } = writer;
+        core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+    }
+}
+
+/// Helper writer which fills a byte buffer, failing once it is full
+struct SliceWriter<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        match self.buf.get_mut(self.len..end) {
+            Some(space) => {
+                space.copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+            None => Err(fmt::Error),
+        }
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        let end = self.len + c.len_utf8();
+        match self.buf.get_mut(self.len..end) {
+            Some(space) => {
+                c.encode_utf8(space);
+                self.len = end;
+                Ok(())
+            }
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+/// A letter, and how many bars of the vinculum it has over it
+#[derive(Clone, Copy)]
+struct Glyph(char, u8);
+
+impl Glyph {
+    /// Helper function adding ``bars`` to the vinculum of a letter
+    const fn raised(self, bars: u8) -> Self {
+        Glyph(self.0, self.1 + bars)
+    }
+}
+
+/// The value multiplied by the frame of ``LargeNumerals::Framed``
+const FRAMED: u64 = 100_000;
+
+/// The value of each decimal place, from the units to the billions
+static POWERS: [u64; 10] = [
+    1,
+    10,
+    100,
+    1000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+];
+
+/// The letters for one, five and ten at each decimal place, from the units to the billions
+static PLACES: [(Glyph, Option<Glyph>, Option<Glyph>); 10] = [
+    (Glyph('I', 0), Some(Glyph('V', 0)), Some(Glyph('X', 0))),
+    (Glyph('X', 0), Some(Glyph('L', 0)), Some(Glyph('C', 0))),
+    (Glyph('C', 0), Some(Glyph('D', 0)), Some(Glyph('M', 0))),
+    (Glyph('M', 0), Some(Glyph('V', 1)), Some(Glyph('X', 1))),
+    (Glyph('X', 1), Some(Glyph('L', 1)), Some(Glyph('C', 1))),
+    (Glyph('C', 1), Some(Glyph('D', 1)), Some(Glyph('M', 1))),
+    (Glyph('M', 1), Some(Glyph('V', 2)), Some(Glyph('X', 2))),
+    (Glyph('X', 2), Some(Glyph('L', 2)), Some(Glyph('C', 2))),
+    (Glyph('C', 2), Some(Glyph('D', 2)), Some(Glyph('M', 2))),
+    (Glyph('M', 2), None, None),
+];
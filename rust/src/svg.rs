@@ -0,0 +1,652 @@
+//! # kalendarium inscriptions in SVG
+//!
+//! An ``Inscription`` draws Roman numerals and ``Kalendarium`` dates as SVG, in a stroke-based
+//! set of inscriptional capitals (capitalis monumentalis) built into the library. No font is
+//! needed, so the same text always gives the same drawing, byte for byte. Text is cut as on a
+//! Roman monument: every letter is a capital, U is cut as V and J as I, macrons are dropped, and
+//! words and abbreviations are divided by an interpunct rather than by a space or a period. The
+//! vinculum of a large numeral is drawn as a real bar over its letters, and a few common
+//! ligatures such as NT and AE can join two letters into one.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{Kalendarium, ParseErrorKind, ParseNumeralError, Result, RomanNumeral};
+use core::fmt::Write;
+
+/// The height of the capitals, in the units of the glyphs
+const CAP_HEIGHT: i32 = 20;
+/// The space between two letters of a word
+const LETTER_SPACE: i32 = 4;
+/// The space between two words, when they are not divided by an interpunct
+const WORD_SPACE: i32 = 12;
+/// The space on either side of an interpunct
+const INTERPUNCT_SPACE: i32 = 6;
+/// The distance between the bars of a vinculum, and between the lowest bar and the capitals
+const BAR_SPACE: i32 = 4;
+/// The margin around the drawing, above the bars and below the tail of Q
+const MARGIN: i32 = 4;
+/// The top of the drawing, with room for a double vinculum and the frame of a framed numeral
+const TOP: i32 = -3 * BAR_SPACE;
+/// The bottom of the drawing, with room for the tail of Q
+const BOTTOM: i32 = CAP_HEIGHT + 2 * MARGIN;
+
+/// The combining overline of the vinculum
+const OVERLINE: char = '\u{305}';
+/// The combining double overline of the double vinculum
+const DOUBLE_OVERLINE: char = '\u{33f}';
+
+/// How an ``Inscription`` is drawn
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let inscription = Inscription::new().height(60).ligatures(true);
+///    let svg: String = inscription.render("MONVMENTVM")?;
+///    assert!(svg.starts_with("<svg "));
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Inscription {
+    height: u32,
+    stroke_width: u32,
+    interpuncts: bool,
+    ligatures: bool,
+}
+
+/// A letter of the built-in capitals, as strokes drawn from point to point
+///
+/// The capitals stand on the baseline at ``CAP_HEIGHT``, with y growing downwards as in SVG, and
+/// run from x = 0 to x = ``width``.
+#[derive(Debug)]
+struct Glyph {
+    width: i32,
+    strokes: &'static [&'static [(i32, i32)]],
+}
+
+/// The built-in capitals
+static GLYPHS: [(char, Glyph); 24] = [
+    (
+        'A',
+        Glyph {
+            width: 14,
+            strokes: &[&[(0, 20), (7, 0), (14, 20)], &[(3, 13), (11, 13)]],
+        },
+    ),
+    (
+        'B',
+        Glyph {
+            width: 12,
+            strokes: &[
+                &[(0, 0), (0, 20)],
+                &[(0, 0), (8, 0), (11, 2), (11, 7), (8, 10), (0, 10)],
+                &[(8, 10), (12, 12), (12, 18), (9, 20), (0, 20)],
+            ],
+        },
+    ),
+    (
+        'C',
+        Glyph {
+            width: 13,
+            strokes: &[&[
+                (13, 3),
+                (10, 0),
+                (4, 0),
+                (0, 5),
+                (0, 15),
+                (4, 20),
+                (10, 20),
+                (13, 17),
+            ]],
+        },
+    ),
+    (
+        'D',
+        Glyph {
+            width: 14,
+            strokes: &[&[(0, 0), (0, 20), (8, 20), (14, 14), (14, 6), (8, 0), (0, 0)]],
+        },
+    ),
+    (
+        'E',
+        Glyph {
+            width: 10,
+            strokes: &[&[(10, 0), (0, 0), (0, 20), (10, 20)], &[(0, 10), (8, 10)]],
+        },
+    ),
+    (
+        'F',
+        Glyph {
+            width: 10,
+            strokes: &[&[(10, 0), (0, 0), (0, 20)], &[(0, 10), (8, 10)]],
+        },
+    ),
+    (
+        'G',
+        Glyph {
+            width: 14,
+            strokes: &[&[
+                (13, 3),
+                (10, 0),
+                (4, 0),
+                (0, 5),
+                (0, 15),
+                (4, 20),
+                (10, 20),
+                (14, 17),
+                (14, 11),
+                (9, 11),
+            ]],
+        },
+    ),
+    (
+        'H',
+        Glyph {
+            width: 12,
+            strokes: &[
+                &[(0, 0), (0, 20)],
+                &[(12, 0), (12, 20)],
+                &[(0, 10), (12, 10)],
+            ],
+        },
+    ),
+    (
+        'I',
+        Glyph {
+            width: 0,
+            strokes: &[&[(0, 0), (0, 20)]],
+        },
+    ),
+    (
+        'K',
+        Glyph {
+            width: 11,
+            strokes: &[&[(0, 0), (0, 20)], &[(11, 0), (0, 10), (11, 20)]],
+        },
+    ),
+    (
+        'L',
+        Glyph {
+            width: 9,
+            strokes: &[&[(0, 0), (0, 20), (9, 20)]],
+        },
+    ),
+    (
+        'M',
+        Glyph {
+            width: 16,
+            strokes: &[&[(0, 20), (1, 0), (8, 20), (15, 0), (16, 20)]],
+        },
+    ),
+    (
+        'N',
+        Glyph {
+            width: 12,
+            strokes: &[&[(0, 20), (0, 0), (12, 20), (12, 0)]],
+        },
+    ),
+    (
+        'O',
+        Glyph {
+            width: 16,
+            strokes: &[&[
+                (5, 0),
+                (11, 0),
+                (16, 5),
+                (16, 15),
+                (11, 20),
+                (5, 20),
+                (0, 15),
+                (0, 5),
+                (5, 0),
+            ]],
+        },
+    ),
+    (
+        'P',
+        Glyph {
+            width: 11,
+            strokes: &[&[(0, 20), (0, 0), (8, 0), (11, 3), (11, 8), (8, 11), (0, 11)]],
+        },
+    ),
+    (
+        'Q',
+        Glyph {
+            width: 16,
+            strokes: &[
+                &[
+                    (5, 0),
+                    (11, 0),
+                    (16, 5),
+                    (16, 15),
+                    (11, 20),
+                    (5, 20),
+                    (0, 15),
+                    (0, 5),
+                    (5, 0),
+                ],
+                &[(9, 17), (17, 24)],
+            ],
+        },
+    ),
+    (
+        'R',
+        Glyph {
+            width: 12,
+            strokes: &[
+                &[(0, 20), (0, 0), (8, 0), (11, 3), (11, 8), (8, 11), (0, 11)],
+                &[(5, 11), (12, 20)],
+            ],
+        },
+    ),
+    (
+        'S',
+        Glyph {
+            width: 11,
+            strokes: &[&[
+                (11, 3),
+                (8, 0),
+                (3, 0),
+                (0, 3),
+                (0, 7),
+                (11, 13),
+                (11, 17),
+                (8, 20),
+                (3, 20),
+                (0, 17),
+            ]],
+        },
+    ),
+    (
+        'T',
+        Glyph {
+            width: 12,
+            strokes: &[&[(0, 0), (12, 0)], &[(6, 0), (6, 20)]],
+        },
+    ),
+    (
+        'V',
+        Glyph {
+            width: 14,
+            strokes: &[&[(0, 0), (7, 20), (14, 0)]],
+        },
+    ),
+    (
+        'X',
+        Glyph {
+            width: 13,
+            strokes: &[&[(0, 0), (13, 20)], &[(13, 0), (0, 20)]],
+        },
+    ),
+    (
+        'Y',
+        Glyph {
+            width: 12,
+            strokes: &[&[(0, 0), (6, 10), (12, 0)], &[(6, 10), (6, 20)]],
+        },
+    ),
+    (
+        'Z',
+        Glyph {
+            width: 11,
+            strokes: &[&[(0, 0), (11, 0), (0, 20), (11, 20)]],
+        },
+    ),
+    // The frame of a framed numeral, e.g. |X̅| for 1,000,000, rising above the vinculum
+    (
+        '|',
+        Glyph {
+            width: 0,
+            strokes: &[&[(0, -2 * BAR_SPACE), (0, 20)]],
+        },
+    ),
+];
+
+/// The ligatures of the built-in capitals, where two letters share a stroke
+static LIGATURES: [(char, char, Glyph); 4] = [
+    (
+        'A',
+        'E',
+        Glyph {
+            width: 18,
+            strokes: &[
+                &[(0, 20), (9, 0), (18, 0)],
+                &[(9, 0), (9, 20), (18, 20)],
+                &[(4, 12), (9, 12)],
+                &[(9, 10), (16, 10)],
+            ],
+        },
+    ),
+    (
+        'H',
+        'E',
+        Glyph {
+            width: 20,
+            strokes: &[
+                &[(0, 0), (0, 20)],
+                &[(0, 10), (12, 10)],
+                &[(20, 0), (12, 0), (12, 20), (20, 20)],
+                &[(12, 10), (18, 10)],
+            ],
+        },
+    ),
+    (
+        'N',
+        'E',
+        Glyph {
+            width: 20,
+            strokes: &[
+                &[(0, 20), (0, 0), (12, 20), (12, 0), (20, 0)],
+                &[(12, 20), (20, 20)],
+                &[(12, 10), (18, 10)],
+            ],
+        },
+    ),
+    (
+        'N',
+        'T',
+        Glyph {
+            width: 18,
+            strokes: &[&[(0, 20), (0, 0), (12, 20), (12, 0)], &[(6, 0), (18, 0)]],
+        },
+    ),
+];
+
+/// Helper struct for a glyph placed in the line, with the bars of the vinculum over it
+struct Placed {
+    letter: char,
+    glyph: &'static Glyph,
+    x: i32,
+    bars: u8,
+    word: usize,
+}
+
+impl Inscription {
+    /// Creates the default inscription: capitals 40 pixels high, with interpuncts and without
+    /// ligatures.
+    #[must_use]
+    pub const fn new() -> Self {
+        Inscription {
+            height: 40,
+            stroke_width: 2,
+            interpuncts: true,
+            ligatures: false,
+        }
+    }
+
+    /// Sets the height of the capitals in pixels. The drawing itself is taller, with room for
+    /// the vinculum above and the tail of Q below.
+    #[must_use]
+    pub const fn height(self, height: u32) -> Self {
+        Inscription { height, ..self }
+    }
+
+    /// Sets the width of the strokes, where the capitals are 20 units high.
+    #[must_use]
+    pub const fn stroke_width(self, stroke_width: u32) -> Self {
+        Inscription {
+            stroke_width,
+            ..self
+        }
+    }
+
+    /// Sets whether words are divided by an interpunct, or only by a space.
+    #[must_use]
+    pub const fn interpuncts(self, interpuncts: bool) -> Self {
+        Inscription {
+            interpuncts,
+            ..self
+        }
+    }
+
+    /// Sets whether the pairs AE, HE, NE and NT are joined into ligatures.
+    #[must_use]
+    pub const fn ligatures(self, ligatures: bool) -> Self {
+        Inscription { ligatures, ..self }
+    }
+
+    /// Draws ``text`` as an SVG document in the built-in capitals.
+    ///
+    /// Spaces and periods divide words. A combining overline (U+0305) draws a bar over the letter
+    /// before it, and a combining double overline (U+033F) two bars. Returns
+    /// ``ParseNumeralError`` of kind ``InvalidCharacter`` at the first character which has no
+    /// glyph, such as a digit.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let svg: String = Inscription::new().render("I̅V̅ a.u.c.")?;
+    ///    assert!(svg.contains("<title>I̅V̅ a.u.c.</title>"));
+    ///    assert_eq!(
+    ///        Inscription::new().render("MMXXV"),
+    ///        Inscription::new().render("mmxxv"),
+    ///    );
+    ///
+    pub fn render(&self, text: &str) -> Result<String, ParseNumeralError> {
+        let (placed, dots, width) = self.layout(text)?;
+
+        let mut path = String::new();
+        for item in &placed {
+            for stroke in item.glyph.strokes {
+                for (i, (x, y)) in stroke.iter().enumerate() {
+                    let command = if i == 0 { 'M' } else { 'L' };
+                    let _ = write!(path, "{command}{} {y}", item.x + x);
+                }
+            }
+        }
+        for (start, end, level) in Self::bars(&placed) {
+            let y = -i32::from(level) * BAR_SPACE;
+            let _ = write!(path, "M{} {y}L{} {y}", start - 1, end + 1);
+        }
+        // A stroke of no length draws a round dot of the stroke width
+        for x in dots {
+            let _ = write!(path, "M{x} {}h0", CAP_HEIGHT / 2);
+        }
+
+        let view_width = width + 2 * MARGIN;
+        let view_height = BOTTOM - TOP;
+        let scale = f64::from(self.height) / f64::from(CAP_HEIGHT);
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"{} {TOP} {view_width} {view_height}\">",
+            f64::from(view_width) * scale,
+            f64::from(view_height) * scale,
+            -MARGIN,
+        );
+        out.push_str("<title>");
+        for character in text.chars() {
+            match character {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                _ => out.push(character),
+            }
+        }
+        out.push_str("</title>");
+        let _ = write!(
+            out,
+            "<path d=\"{path}\" fill=\"none\" stroke=\"currentColor\" stroke-width=\"{}\" \
+             stroke-linecap=\"round\" stroke-linejoin=\"round\"/></svg>",
+            self.stroke_width,
+        );
+        Ok(out)
+    }
+
+    /// Helper function to place the glyphs of ``text`` along the line, returning them with the
+    /// positions of the interpuncts and the width of the line
+    fn layout(&self, text: &str) -> Result<(Vec<Placed>, Vec<i32>, i32), ParseNumeralError> {
+        let mut placed: Vec<Placed> = Vec::new();
+        let mut dots = Vec::new();
+        let mut x = 0;
+        let mut word = 0;
+        let mut divided = false;
+        for (position, character) in text.char_indices() {
+            let invalid = ParseNumeralError::new(ParseErrorKind::InvalidCharacter, position);
+            if matches!(character, OVERLINE | DOUBLE_OVERLINE) {
+                match placed.last_mut() {
+                    Some(last) if !divided && last.letter != '|' => {
+                        last.bars += if character == OVERLINE { 1 } else { 2 };
+                    }
+                    _ => return Err(invalid),
+                }
+                continue;
+            }
+            if character.is_whitespace() || character == '.' {
+                divided = true;
+                continue;
+            }
+            let letters: &[char] = match Self::base_letter(character).ok_or(invalid)? {
+                'W' => &['V', 'V'],
+                letter => &[letter; 1][..],
+            };
+            // Leading and trailing dividers are dropped, as is the final period of a.u.c.
+            if divided && !placed.is_empty() {
+                word += 1;
+                if self.interpuncts {
+                    dots.push(x - LETTER_SPACE + INTERPUNCT_SPACE);
+                    x += 2 * INTERPUNCT_SPACE - LETTER_SPACE;
+                } else {
+                    x += WORD_SPACE - LETTER_SPACE;
+                }
+            }
+            divided = false;
+            for &letter in letters {
+                let ligature = placed
+                    .last_mut()
+                    .filter(|last| self.ligatures && last.word == word && last.bars == 0)
+                    .and_then(|last| {
+                        LIGATURES
+                            .iter()
+                            .find(|(first, second, _)| *first == last.letter && *second == letter)
+                            .map(|(_, _, glyph)| (last, glyph))
+                    });
+                if let Some((last, glyph)) = ligature {
+                    last.letter = letter;
+                    last.glyph = glyph;
+                    x = last.x + glyph.width + LETTER_SPACE;
+                    continue;
+                }
+                let glyph = GLYPHS
+                    .iter()
+                    .find(|(glyph_letter, _)| *glyph_letter == letter)
+                    .map(|(_, glyph)| glyph)
+                    .ok_or(invalid)?;
+                placed.push(Placed {
+                    letter,
+                    glyph,
+                    x,
+                    bars: 0,
+                    word,
+                });
+                x += glyph.width + LETTER_SPACE;
+            }
+        }
+        let width = if placed.is_empty() {
+            0
+        } else {
+            x - LETTER_SPACE
+        };
+        Ok((placed, dots, width))
+    }
+
+    /// Helper function for the capital a character is cut as, if any
+    fn base_letter(character: char) -> Option<char> {
+        let mut upper = character.to_uppercase();
+        let letter = match (upper.next()?, upper.next()) {
+            (letter, None) => letter,
+            _ => return None,
+        };
+        match letter {
+            'Ā' | 'Ă' => Some('A'),
+            'Ē' | 'Ĕ' => Some('E'),
+            'Ī' | 'Ĭ' | 'J' => Some('I'),
+            'Ō' | 'Ŏ' => Some('O'),
+            'Ū' | 'Ŭ' | 'U' => Some('V'),
+            'Ȳ' => Some('Y'),
+            'A'..='Z' | '|' => Some(letter),
+            _ => None,
+        }
+    }
+
+    /// Helper function for the bars of the vinculum: runs of neighbouring glyphs in one word
+    /// which carry at least ``level`` bars, as the start and end of the run and the level
+    fn bars(placed: &[Placed]) -> Vec<(i32, i32, u8)> {
+        let mut bars = Vec::new();
+        let most = placed.iter().map(|item| item.bars).max().unwrap_or(0);
+        for level in 1..=most {
+            let mut run: Option<(i32, i32, usize)> = None;
+            for item in placed {
+                let end = item.x + item.glyph.width;
+                run = match run {
+                    _ if item.bars < level => {
+                        bars.extend(run.map(|(start, end, _)| (start, end, level)));
+                        None
+                    }
+                    Some((start, _, word)) if word == item.word => Some((start, end, word)),
+                    _ => {
+                        bars.extend(run.map(|(start, end, _)| (start, end, level)));
+                        Some((item.x, end, item.word))
+                    }
+                };
+            }
+            bars.extend(run.map(|(start, end, _)| (start, end, level)));
+        }
+        bars
+    }
+}
+
+impl Default for Inscription {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RomanNumeral {
+    /// Draws a ``RomanNumeral`` as an SVG inscription, in capitals with a bar over each letter of
+    /// the vinculum.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: RomanNumeral = RomanNumeral::new(4600)?;
+    ///    let svg: String = num.to_svg(&Inscription::new());
+    ///    assert!(svg.contains("<title>I̅V̅DC</title>"));
+    ///
+    #[must_use]
+    pub fn to_svg(&self, inscription: &Inscription) -> String {
+        // Every letter of an uppercase numeral has a glyph
+        inscription
+            .render(&self.to_uppercase())
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Kalendarium {
+    /// Draws a ``Kalendarium`` as an SVG inscription, e.g. ANTE·DIEM·X·KAL·IVN for ante diem X
+    /// Kal. Iun.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let kal: Kalendarium = Kalendarium::new("2025", "5", "23").unwrap();
+    ///    let svg: String = kal.to_svg(&Inscription::new().ligatures(true));
+    ///    assert!(svg.starts_with("<svg "));
+    ///
+    #[must_use]
+    pub fn to_svg(&self, inscription: &Inscription) -> String {
+        // Every letter of a date has a glyph
+        inscription
+            .render(&self.to_str())
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
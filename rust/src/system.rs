@@ -0,0 +1,486 @@
+//! # kalendarium numeral systems
+//!
+//! The ``NumeralSystem`` trait describes what every numeral type of this crate offers: its range,
+//! how to write it in one of its styles, how to parse it, and the names of its supported styles.
+//! Code which is generic over ``NumeralSystem`` works with ``RomanNumeral``, ``GreekNumeral``,
+//! ``AtticNumeral``, ``HebrewNumeral``, ``CyrillicNumeral`` and ``EtruscanNumeral`` alike.
+//!
+//! ``NumeralFormat`` picks a system and style at runtime from a configuration string such as
+//! ``roman``, ``roman:lowercase``, ``greek:diophantus``, ``hebrew:plain``, ``cyrillic:plain`` or
+//! ``etruscan:left-to-right``.
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+use crate::{
+    AdditiveForms, Apostrophus, AtticNumeral, AtticUnit, CyrillicNumeral, CyrillicStyle, Direction,
+    EtruscanNumeral, GreekNumeral, GreekStyle, HebrewNumeral, HebrewStyle, LargeNumerals, Letters,
+    Myriads, NumeralStyle, OutOfRangeError, ParseFormatError, ParseNumeralError, Result,
+    RomanNumeral,
+};
+use core::fmt::{self, Write};
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+/// A system of numerals, which can be written in several styles and parsed back
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    fn largest<N: NumeralSystem>() -> String {
+///        N::from_value(N::MAX).unwrap().to_string()
+///    }
+///
+///    assert_eq!("͵ΘϠϞΘʹΜ͵ΘϠϞΘʹ", largest::<GreekNumeral>());
+///
+pub trait NumeralSystem:
+    Copy + Ord + fmt::Debug + fmt::Display + FromStr<Err = ParseNumeralError>
+{
+    /// The description of how to write out a numeral
+    type Style: Copy + Eq + fmt::Debug + 'static;
+
+    /// The name of the system, as used by ``NumeralFormat``
+    const NAME: &'static str;
+    /// The value of the smallest numeral
+    const MIN: u64;
+    /// The value of the largest numeral
+    const MAX: u64;
+    /// The named styles of the system, the first being the default
+    const STYLES: &'static [(&'static str, Self::Style)];
+
+    /// Creates a numeral for any value from ``MIN`` to ``MAX``.
+    fn from_value(value: u64) -> Result<Self, OutOfRangeError>;
+
+    /// Returns the value of the numeral.
+    fn value(self) -> u64;
+
+    /// Writes the numeral in ``style`` to any ``fmt::Write``, without allocating.
+    fn write_styled<W: Write>(&self, style: &Self::Style, out: &mut W) -> fmt::Result;
+
+    /// Parses a numeral from a string.
+    ///
+    /// Not every style can be read back: a ``RomanNumeral`` is only parsed from the canonical
+    /// forms accepted by ``RomanNumeral::parse``. Use ``parse_styled`` for the others.
+    fn parse_numeral(s: &str) -> Result<Self, ParseNumeralError> {
+        s.parse()
+    }
+
+    /// Parses a numeral from a string, accepting the forms written in ``style`` as well as those
+    /// accepted by ``parse_numeral``.
+    fn parse_styled(s: &str, _style: &Self::Style) -> Result<Self, ParseNumeralError> {
+        Self::parse_numeral(s)
+    }
+
+    /// Converts the numeral to a string written in ``style``.
+    ///
+    /// Returns ``OutOfRangeError`` if the style cannot write the numeral, such as zero in a
+    /// Roman style with ``Zero::Error``.
+    #[cfg(feature = "std")]
+    fn format_styled(&self, style: &Self::Style) -> Result<String, OutOfRangeError> {
+        let mut out = String::new();
+        match self.write_styled(style, &mut out) {
+            Ok(()) => Ok(out),
+            Err(_) => Err(OutOfRangeError),
+        }
+    }
+
+    /// The range of the values of the numerals.
+    #[must_use]
+    fn value_range() -> RangeInclusive<u64> {
+        Self::MIN..=Self::MAX
+    }
+
+    /// Returns the style with ``name``, if the system supports it.
+    #[must_use]
+    fn style(name: &str) -> Option<Self::Style> {
+        Self::STYLES
+            .iter()
+            .find(|&&(style, _)| style == name)
+            .map(|&(_, style)| style)
+    }
+
+    /// Returns the name of ``style``, if it is one of the named styles.
+    #[must_use]
+    fn style_name(style: &Self::Style) -> Option<&'static str> {
+        Self::STYLES
+            .iter()
+            .find(|(_, named)| named == style)
+            .map(|&(name, _)| name)
+    }
+}
+
+impl NumeralSystem for RomanNumeral {
+    type Style = NumeralStyle;
+
+    const NAME: &'static str = "roman";
+    const MIN: u64 = crate::MIN;
+    const MAX: u64 = crate::MAX;
+    const STYLES: &'static [(&'static str, NumeralStyle)] = &[
+        ("uppercase", NumeralStyle::UPPERCASE),
+        ("lowercase", NumeralStyle::LOWERCASE),
+        ("additive", NumeralStyle::new().additive(AdditiveForms::ALL)),
+        ("clock", NumeralStyle::new().additive(AdditiveForms::CLOCK)),
+        (
+            "apostrophus",
+            NumeralStyle::new().large(LargeNumerals::Apostrophus(Apostrophus::Unicode)),
+        ),
+        (
+            "ascii",
+            NumeralStyle::new().large(LargeNumerals::AsciiVinculum),
+        ),
+        (
+            "double-vinculum",
+            NumeralStyle::new().large(LargeNumerals::DoubleVinculum),
+        ),
+        ("framed", NumeralStyle::new().large(LargeNumerals::Framed)),
+        (
+            "number-forms",
+            NumeralStyle::new().letters(Letters::NumberForms),
+        ),
+    ];
+
+    fn from_value(value: u64) -> Result<Self, OutOfRangeError> {
+        RomanNumeral::new(value)
+    }
+
+    fn value(self) -> u64 {
+        self.0
+    }
+
+    fn write_styled<W: Write>(&self, style: &NumeralStyle, out: &mut W) -> fmt::Result {
+        self.write_with(style, out)
+    }
+
+    fn parse_styled(s: &str, style: &NumeralStyle) -> Result<Self, ParseNumeralError> {
+        RomanNumeral::parse_with(s, style)
+    }
+}
+
+impl NumeralSystem for GreekNumeral {
+    type Style = GreekStyle;
+
+    const NAME: &'static str = "greek";
+    const MIN: u64 = GreekNumeral::MIN as u64;
+    const MAX: u64 = GreekNumeral::MAX as u64;
+    const STYLES: &'static [(&'static str, GreekStyle)] = &[
+        ("uppercase", GreekStyle::UPPERCASE),
+        ("lowercase", GreekStyle::LOWERCASE),
+        ("diophantus", GreekStyle::new().myriads(Myriads::Diophantus)),
+        (
+            "diophantus-lowercase",
+            GreekStyle::LOWERCASE.myriads(Myriads::Diophantus),
+        ),
+    ];
+
+    fn from_value(value: u64) -> Result<Self, OutOfRangeError> {
+        GreekNumeral::try_from(value)
+    }
+
+    fn value(self) -> u64 {
+        u64::from(self.0)
+    }
+
+    fn write_styled<W: Write>(&self, style: &GreekStyle, out: &mut W) -> fmt::Result {
+        self.write_with(style, out)
+    }
+}
+
+impl NumeralSystem for HebrewNumeral {
+    type Style = HebrewStyle;
+
+    const NAME: &'static str = "hebrew";
+    const MIN: u64 = HebrewNumeral::MIN as u64;
+    const MAX: u64 = HebrewNumeral::MAX as u64;
+    const STYLES: &'static [(&'static str, HebrewStyle)] = &[
+        ("punctuated", HebrewStyle::new()),
+        ("plain", HebrewStyle::new().punctuation(false)),
+        ("final-forms", HebrewStyle::new().final_forms(true)),
+    ];
+
+    fn from_value(value: u64) -> Result<Self, OutOfRangeError> {
+        HebrewNumeral::try_from(value)
+    }
+
+    fn value(self) -> u64 {
+        u64::from(self.0)
+    }
+
+    fn write_styled<W: Write>(&self, style: &HebrewStyle, out: &mut W) -> fmt::Result {
+        self.write_with(style, out)
+    }
+}
+
+impl NumeralSystem for CyrillicNumeral {
+    type Style = CyrillicStyle;
+
+    const NAME: &'static str = "cyrillic";
+    const MIN: u64 = CyrillicNumeral::MIN as u64;
+    const MAX: u64 = CyrillicNumeral::MAX as u64;
+    const STYLES: &'static [(&'static str, CyrillicStyle)] = &[
+        ("titlo", CyrillicStyle::new()),
+        ("plain", CyrillicStyle::new().titlo(false)),
+    ];
+
+    fn from_value(value: u64) -> Result<Self, OutOfRangeError> {
+        CyrillicNumeral::try_from(value)
+    }
+
+    fn value(self) -> u64 {
+        u64::from(self.0)
+    }
+
+    fn write_styled<W: Write>(&self, style: &CyrillicStyle, out: &mut W) -> fmt::Result {
+        self.write_with(style, out)
+    }
+}
+
+impl NumeralSystem for EtruscanNumeral {
+    type Style = Direction;
+
+    const NAME: &'static str = "etruscan";
+    const MIN: u64 = EtruscanNumeral::MIN as u64;
+    const MAX: u64 = EtruscanNumeral::MAX as u64;
+    const STYLES: &'static [(&'static str, Direction)] = &[
+        ("right-to-left", Direction::RightToLeft),
+        ("left-to-right", Direction::LeftToRight),
+    ];
+
+    fn from_value(value: u64) -> Result<Self, OutOfRangeError> {
+        EtruscanNumeral::try_from(value)
+    }
+
+    fn value(self) -> u64 {
+        u64::from(self.0)
+    }
+
+    fn write_styled<W: Write>(&self, style: &Direction, out: &mut W) -> fmt::Result {
+        self.write_with(*style, out)
+    }
+}
+
+impl NumeralSystem for AtticNumeral {
+    type Style = AtticUnit;
+
+    const NAME: &'static str = "attic";
+    const MIN: u64 = AtticNumeral::MIN as u64;
+    const MAX: u64 = AtticNumeral::MAX as u64;
+    const STYLES: &'static [(&'static str, AtticUnit)] = &[
+        ("number", AtticUnit::Number),
+        ("drachmas", AtticUnit::Drachmas),
+        ("talents", AtticUnit::Talents),
+    ];
+
+    fn from_value(value: u64) -> Result<Self, OutOfRangeError> {
+        AtticNumeral::try_from(value)
+    }
+
+    fn value(self) -> u64 {
+        u64::from(self.0)
+    }
+
+    fn write_styled<W: Write>(&self, style: &AtticUnit, out: &mut W) -> fmt::Result {
+        self.write_with(*style, out)
+    }
+}
+
+/// A numeral system and one of its styles, chosen at runtime
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let format: NumeralFormat = "roman:lowercase".parse()?;
+///    assert_eq!("xlii", format.format(42)?);
+///    assert_eq!(Ok(42), format.parse("xlii"));
+///
+///    let format: NumeralFormat = "hebrew:plain".parse()?;
+///    assert_eq!("מב", format.format(42)?);
+///
+///    let format: NumeralFormat = "cyrillic:plain".parse()?;
+///    assert_eq!("мв", format.format(42)?);
+///
+///    let format: NumeralFormat = "etruscan:left-to-right".parse()?;
+///    assert_eq!("𐌢𐌢𐌢𐌢𐌠𐌠", format.format(42)?);
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NumeralFormat {
+    /// Roman numerals
+    Roman(NumeralStyle),
+    /// Greek alphabetic numerals
+    Greek(GreekStyle),
+    /// Attic acrophonic numerals
+    Attic(AtticUnit),
+    /// Hebrew alphabetic numerals
+    Hebrew(HebrewStyle),
+    /// Cyrillic alphabetic numerals
+    Cyrillic(CyrillicStyle),
+    /// Etruscan numerals
+    Etruscan(Direction),
+}
+
+impl NumeralFormat {
+    /// The names of the supported systems
+    pub const SYSTEMS: [&'static str; 6] = [
+        RomanNumeral::NAME,
+        GreekNumeral::NAME,
+        AtticNumeral::NAME,
+        HebrewNumeral::NAME,
+        CyrillicNumeral::NAME,
+        EtruscanNumeral::NAME,
+    ];
+
+    /// Returns the name of the system.
+    #[must_use]
+    pub const fn system(&self) -> &'static str {
+        match self {
+            NumeralFormat::Roman(_) => RomanNumeral::NAME,
+            NumeralFormat::Greek(_) => GreekNumeral::NAME,
+            NumeralFormat::Attic(_) => AtticNumeral::NAME,
+            NumeralFormat::Hebrew(_) => HebrewNumeral::NAME,
+            NumeralFormat::Cyrillic(_) => CyrillicNumeral::NAME,
+            NumeralFormat::Etruscan(_) => EtruscanNumeral::NAME,
+        }
+    }
+
+    /// Returns the name of the style, if it is one of the named styles of the system.
+    #[must_use]
+    pub fn style_name(&self) -> Option<&'static str> {
+        match self {
+            NumeralFormat::Roman(style) => RomanNumeral::style_name(style),
+            NumeralFormat::Greek(style) => GreekNumeral::style_name(style),
+            NumeralFormat::Attic(unit) => AtticNumeral::style_name(unit),
+            NumeralFormat::Hebrew(style) => HebrewNumeral::style_name(style),
+            NumeralFormat::Cyrillic(style) => CyrillicNumeral::style_name(style),
+            NumeralFormat::Etruscan(direction) => EtruscanNumeral::style_name(direction),
+        }
+    }
+
+    /// The range of the values the system can write.
+    #[must_use]
+    pub fn range(&self) -> RangeInclusive<u64> {
+        match self {
+            NumeralFormat::Roman(_) => RomanNumeral::value_range(),
+            NumeralFormat::Greek(_) => GreekNumeral::value_range(),
+            NumeralFormat::Attic(_) => AtticNumeral::value_range(),
+            NumeralFormat::Hebrew(_) => HebrewNumeral::value_range(),
+            NumeralFormat::Cyrillic(_) => CyrillicNumeral::value_range(),
+            NumeralFormat::Etruscan(_) => EtruscanNumeral::value_range(),
+        }
+    }
+
+    /// Converts ``value`` to a string in the system and style.
+    ///
+    /// Returns ``OutOfRangeError`` if ``value`` is outside the range of the system, or if the
+    /// style cannot write it.
+    #[cfg(feature = "std")]
+    pub fn format(&self, value: u64) -> Result<String, OutOfRangeError> {
+        let mut out = String::new();
+        match self.write(value, &mut out) {
+            Ok(()) => Ok(out),
+            Err(_) => Err(OutOfRangeError),
+        }
+    }
+
+    /// Writes ``value`` in the system and style to any ``fmt::Write``, without allocating.
+    ///
+    /// Returns ``fmt::Error`` if ``out`` does, or if ``value`` cannot be written.
+    pub fn write<W: Write>(&self, value: u64, out: &mut W) -> fmt::Result {
+        match self {
+            NumeralFormat::Roman(style) => Self::write_value::<RomanNumeral, W>(value, style, out),
+            NumeralFormat::Greek(style) => Self::write_value::<GreekNumeral, W>(value, style, out),
+            NumeralFormat::Attic(unit) => Self::write_value::<AtticNumeral, W>(value, unit, out),
+            NumeralFormat::Hebrew(style) => {
+                Self::write_value::<HebrewNumeral, W>(value, style, out)
+            }
+            NumeralFormat::Cyrillic(style) => {
+                Self::write_value::<CyrillicNumeral, W>(value, style, out)
+            }
+            NumeralFormat::Etruscan(direction) => {
+                Self::write_value::<EtruscanNumeral, W>(value, direction, out)
+            }
+        }
+    }
+
+    /// Parses a numeral of the system, returning its value.
+    ///
+    /// Accepts the same strings as ``NumeralSystem::parse_styled`` with the style, so that
+    /// whatever ``format`` writes is read back.
+    pub fn parse(&self, s: &str) -> Result<u64, ParseNumeralError> {
+        match self {
+            NumeralFormat::Roman(style) => Self::parse_value::<RomanNumeral>(s, style),
+            NumeralFormat::Greek(style) => Self::parse_value::<GreekNumeral>(s, style),
+            NumeralFormat::Attic(unit) => Self::parse_value::<AtticNumeral>(s, unit),
+            NumeralFormat::Hebrew(style) => Self::parse_value::<HebrewNumeral>(s, style),
+            NumeralFormat::Cyrillic(style) => Self::parse_value::<CyrillicNumeral>(s, style),
+            NumeralFormat::Etruscan(direction) => {
+                Self::parse_value::<EtruscanNumeral>(s, direction)
+            }
+        }
+    }
+
+    /// Helper function to write a value with any system
+    fn write_value<N: NumeralSystem, W: Write>(
+        value: u64,
+        style: &N::Style,
+        out: &mut W,
+    ) -> fmt::Result {
+        match N::from_value(value) {
+            Ok(num) => num.write_styled(style, out),
+            Err(_) => Err(fmt::Error),
+        }
+    }
+
+    /// Helper function to parse a value with any system
+    fn parse_value<N: NumeralSystem>(s: &str, style: &N::Style) -> Result<u64, ParseNumeralError> {
+        N::parse_styled(s, style).map(N::value)
+    }
+
+    /// Helper function to look up a named style, or the default style if there is no name
+    fn named_style<N: NumeralSystem>(name: Option<&str>) -> Result<N::Style, ParseFormatError> {
+        match name {
+            Some(name) => N::style(name).ok_or(ParseFormatError),
+            None => Ok(N::STYLES[0].1),
+        }
+    }
+}
+
+impl FromStr for NumeralFormat {
+    type Err = ParseFormatError;
+
+    /// Parses a ``NumeralFormat`` from the name of a system, optionally followed by a colon and
+    /// the name of a style, e.g. ``greek`` or ``greek:lowercase``.
+    ///
+    /// Returns ``NumeralFormat`` or ``ParseFormatError``.
+    fn from_str(s: &str) -> Result<Self, ParseFormatError> {
+        let (system, style) = match s.split_once(':') {
+            Some((system, style)) => (system, Some(style)),
+            None => (s, None),
+        };
+        match system {
+            RomanNumeral::NAME => {
+                Self::named_style::<RomanNumeral>(style).map(NumeralFormat::Roman)
+            }
+            GreekNumeral::NAME => {
+                Self::named_style::<GreekNumeral>(style).map(NumeralFormat::Greek)
+            }
+            AtticNumeral::NAME => {
+                Self::named_style::<AtticNumeral>(style).map(NumeralFormat::Attic)
+            }
+            HebrewNumeral::NAME => {
+                Self::named_style::<HebrewNumeral>(style).map(NumeralFormat::Hebrew)
+            }
+            CyrillicNumeral::NAME => {
+                Self::named_style::<CyrillicNumeral>(style).map(NumeralFormat::Cyrillic)
+            }
+            EtruscanNumeral::NAME => {
+                Self::named_style::<EtruscanNumeral>(style).map(NumeralFormat::Etruscan)
+            }
+            _ => Err(ParseFormatError),
+        }
+    }
+}
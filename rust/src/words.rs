@@ -0,0 +1,710 @@
+//! # kalendarium Latin number words
+//!
+//! Numbers written out in Latin words, as the four series of the grammars: the cardinals (ūnus,
+//! duo, trēs), the ordinals (prīmus, secundus, tertius), the distributives (singulī, bīnī,
+//! ternī) and the numeral adverbs (semel, bis, ter). The cardinals one to three, the hundreds
+//! from two hundred and every ordinal and distributive decline by gender and case; the other
+//! cardinals and the adverbs do not.
+//!
+//! Compounds are written with the largest part first and without et, e.g. vīgintī ūnus for 21,
+//! and the eights and nines below ninety are subtracted from the next ten, e.g. duodēvīgintī for
+//! 18. Thousands are counted with the noun mīlia, e.g. duo mīlia for 2000, with mīllēsimus
+//! after a numeral adverb for the ordinals, e.g. bis mīllēsimus, and with mīliēs for the adverbs.
+//! Values from 1 to 999,999 are supported.
+//!
+//! https://en.wikipedia.org/wiki/Latin_numerals
+//!
+//! ## License
+//!
+//! GNU GPL 3
+
+#[cfg(feature = "std")]
+use crate::OutOfRangeError;
+use crate::{ParseErrorKind, ParseNumeralError, Result, RomanNumeral};
+use core::fmt::{self, Write};
+
+/// The series of Latin number words
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum WordKind {
+    /// How many, e.g. trēs for 3
+    #[default]
+    Cardinal,
+    /// Which in order, e.g. tertius for 3
+    Ordinal,
+    /// How many each, e.g. ternī for 3
+    Distributive,
+    /// How many times, e.g. ter for 3
+    Adverb,
+}
+
+/// The grammatical gender of a declined number word
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Gender {
+    /// e.g. ūnus, prīmus
+    #[default]
+    Masculine,
+    /// e.g. ūna, prīma
+    Feminine,
+    /// e.g. ūnum, prīmum
+    Neuter,
+}
+
+/// The grammatical case of a declined number word
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum GrammaticalCase {
+    /// e.g. duo
+    #[default]
+    Nominative,
+    /// e.g. duōrum
+    Genitive,
+    /// e.g. duōbus
+    Dative,
+    /// e.g. duōs
+    Accusative,
+    /// e.g. duōbus
+    Ablative,
+    /// e.g. duo, prīme
+    Vocative,
+}
+
+/// A complete description of how to write out a number in Latin words
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    const ORDINAL: LatinWords = LatinWords::new().kind(WordKind::Ordinal).gender(Gender::Feminine);
+///
+///    assert_eq!("vīcēsima prīma", ORDINAL.format(21)?);
+///    assert_eq!("bis mīllēsima vīcēsima quārta", ORDINAL.format(2024)?);
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LatinWords {
+    kind: WordKind,
+    gender: Gender,
+    case: GrammaticalCase,
+}
+
+/// Helper type for the endings of a declined word, by gender and then by case
+type Endings = [[&'static str; 6]; 3];
+
+/// Helper type for a word: its stem and the endings it takes
+type Word = (&'static str, &'static Endings);
+
+const INDECLINABLE: Endings = [[""; 6]; 3];
+
+const UNUS: Endings = [
+    ["us", "īus", "ī", "um", "ō", "e"],
+    ["a", "īus", "ī", "am", "ā", "a"],
+    ["um", "īus", "ī", "um", "ō", "um"],
+];
+
+const DUO: Endings = [
+    ["o", "ōrum", "ōbus", "ōs", "ōbus", "o"],
+    ["ae", "ārum", "ābus", "ās", "ābus", "ae"],
+    ["o", "ōrum", "ōbus", "o", "ōbus", "o"],
+];
+
+const TRES: Endings = [
+    ["ēs", "ium", "ibus", "ēs", "ibus", "ēs"],
+    ["ēs", "ium", "ibus", "ēs", "ibus", "ēs"],
+    ["ia", "ium", "ibus", "ia", "ibus", "ia"],
+];
+
+const SINGULAR: Endings = [
+    ["us", "ī", "ō", "um", "ō", "e"],
+    ["a", "ae", "ae", "am", "ā", "a"],
+    ["um", "ī", "ō", "um", "ō", "um"],
+];
+
+const PLURAL: Endings = [
+    ["ī", "ōrum", "īs", "ōs", "īs", "ī"],
+    ["ae", "ārum", "īs", "ās", "īs", "ae"],
+    ["a", "ōrum", "īs", "a", "īs", "a"],
+];
+
+/// mīlia is a neuter noun, whatever the gender of the number
+const MILIA: Word = (
+    "mīli",
+    &[
+        ["a", "um", "bus", "a", "bus", "a"],
+        ["a", "um", "bus", "a", "bus", "a"],
+        ["a", "um", "bus", "a", "bus", "a"],
+    ],
+);
+const MILLE: Word = ("mīlle", &INDECLINABLE);
+const MILLESIMUS: Word = ("mīllēsim", &SINGULAR);
+const MILIES: Word = ("mīliēs", &INDECLINABLE);
+
+/// The prefixes which subtract two and one from the next ten
+const SUBTRACTIVE: [&str; 2] = ["duodē", "ūndē"];
+
+/// One to nine, for each ``WordKind``
+const UNITS: [[Word; 9]; 4] = [
+    [
+        ("ūn", &UNUS),
+        ("du", &DUO),
+        ("tr", &TRES),
+        ("quattuor", &INDECLINABLE),
+        ("quīnque", &INDECLINABLE),
+        ("sex", &INDECLINABLE),
+        ("septem", &INDECLINABLE),
+        ("octō", &INDECLINABLE),
+        ("novem", &INDECLINABLE),
+    ],
+    [
+        ("prīm", &SINGULAR),
+        ("secund", &SINGULAR),
+        ("terti", &SINGULAR),
+        ("quārt", &SINGULAR),
+        ("quīnt", &SINGULAR),
+        ("sext", &SINGULAR),
+        ("septim", &SINGULAR),
+        ("octāv", &SINGULAR),
+        ("nōn", &SINGULAR),
+    ],
+    [
+        ("singul", &PLURAL),
+        ("bīn", &PLURAL),
+        ("tern", &PLURAL),
+        ("quatern", &PLURAL),
+        ("quīn", &PLURAL),
+        ("sēn", &PLURAL),
+        ("septēn", &PLURAL),
+        ("octōn", &PLURAL),
+        ("novēn", &PLURAL),
+    ],
+    [
+        ("semel", &INDECLINABLE),
+        ("bis", &INDECLINABLE),
+        ("ter", &INDECLINABLE),
+        ("quater", &INDECLINABLE),
+        ("quīnquiēs", &INDECLINABLE),
+        ("sexiēs", &INDECLINABLE),
+        ("septiēs", &INDECLINABLE),
+        ("octiēs", &INDECLINABLE),
+        ("noviēs", &INDECLINABLE),
+    ],
+];
+
+/// Eleven to seventeen, for each ``WordKind``, where they are a single word. The other ordinals
+/// and distributives join the unit and ten, e.g. tertius decimus for 13.
+const TEENS: [[Option<Word>; 7]; 4] = [
+    [
+        Some(("ūndecim", &INDECLINABLE)),
+        Some(("duodecim", &INDECLINABLE)),
+        Some(("tredecim", &INDECLINABLE)),
+        Some(("quattuordecim", &INDECLINABLE)),
+        Some(("quīndecim", &INDECLINABLE)),
+        Some(("sēdecim", &INDECLINABLE)),
+        Some(("septendecim", &INDECLINABLE)),
+    ],
+    [
+        Some(("ūndecim", &SINGULAR)),
+        Some(("duodecim", &SINGULAR)),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        Some(("ūndēn", &PLURAL)),
+        Some(("duodēn", &PLURAL)),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        Some(("ūndeciēs", &INDECLINABLE)),
+        Some(("duodeciēs", &INDECLINABLE)),
+        Some(("terdeciēs", &INDECLINABLE)),
+        Some(("quaterdeciēs", &INDECLINABLE)),
+        Some(("quīndeciēs", &INDECLINABLE)),
+        Some(("sēdeciēs", &INDECLINABLE)),
+        Some(("septiēsdeciēs", &INDECLINABLE)),
+    ],
+];
+
+/// Ten to ninety, for each ``WordKind``
+const TENS: [[Word; 9]; 4] = [
+    [
+        ("decem", &INDECLINABLE),
+        ("vīgintī", &INDECLINABLE),
+        ("trīgintā", &INDECLINABLE),
+        ("quadrāgintā", &INDECLINABLE),
+        ("quīnquāgintā", &INDECLINABLE),
+        ("sexāgintā", &INDECLINABLE),
+        ("septuāgintā", &INDECLINABLE),
+        ("octōgintā", &INDECLINABLE),
+        ("nōnāgintā", &INDECLINABLE),
+    ],
+    [
+        ("decim", &SINGULAR),
+        ("vīcēsim", &SINGULAR),
+        ("trīcēsim", &SINGULAR),
+        ("quadrāgēsim", &SINGULAR),
+        ("quīnquāgēsim", &SINGULAR),
+        ("sexāgēsim", &SINGULAR),
+        ("septuāgēsim", &SINGULAR),
+        ("octōgēsim", &SINGULAR),
+        ("nōnāgēsim", &SINGULAR),
+    ],
+    [
+        ("dēn", &PLURAL),
+        ("vīcēn", &PLURAL),
+        ("trīcēn", &PLURAL),
+        ("quadrāgēn", &PLURAL),
+        ("quīnquāgēn", &PLURAL),
+        ("sexāgēn", &PLURAL),
+        ("septuāgēn", &PLURAL),
+        ("octōgēn", &PLURAL),
+        ("nōnāgēn", &PLURAL),
+    ],
+    [
+        ("deciēs", &INDECLINABLE),
+        ("vīciēs", &INDECLINABLE),
+        ("trīciēs", &INDECLINABLE),
+        ("quadrāgiēs", &INDECLINABLE),
+        ("quīnquāgiēs", &INDECLINABLE),
+        ("sexāgiēs", &INDECLINABLE),
+        ("septuāgiēs", &INDECLINABLE),
+        ("octōgiēs", &INDECLINABLE),
+        ("nōnāgiēs", &INDECLINABLE),
+    ],
+];
+
+/// One hundred to nine hundred, for each ``WordKind``
+const HUNDREDS: [[Word; 9]; 4] = [
+    [
+        ("centum", &INDECLINABLE),
+        ("ducent", &PLURAL),
+        ("trecent", &PLURAL),
+        ("quadringent", &PLURAL),
+        ("quīngent", &PLURAL),
+        ("sescent", &PLURAL),
+        ("septingent", &PLURAL),
+        ("octingent", &PLURAL),
+        ("nōngent", &PLURAL),
+    ],
+    [
+        ("centēsim", &SINGULAR),
+        ("ducentēsim", &SINGULAR),
+        ("trecentēsim", &SINGULAR),
+        ("quadringentēsim", &SINGULAR),
+        ("quīngentēsim", &SINGULAR),
+        ("sescentēsim", &SINGULAR),
+        ("septingentēsim", &SINGULAR),
+        ("octingentēsim", &SINGULAR),
+        ("nōngentēsim", &SINGULAR),
+    ],
+    [
+        ("centēn", &PLURAL),
+        ("ducēn", &PLURAL),
+        ("trecēn", &PLURAL),
+        ("quadringēn", &PLURAL),
+        ("quīngēn", &PLURAL),
+        ("sescēn", &PLURAL),
+        ("septingēn", &PLURAL),
+        ("octingēn", &PLURAL),
+        ("nōngēn", &PLURAL),
+    ],
+    [
+        ("centiēs", &INDECLINABLE),
+        ("ducentiēs", &INDECLINABLE),
+        ("trecentiēs", &INDECLINABLE),
+        ("quadringentiēs", &INDECLINABLE),
+        ("quīngentiēs", &INDECLINABLE),
+        ("sescentiēs", &INDECLINABLE),
+        ("septingentiēs", &INDECLINABLE),
+        ("octingentiēs", &INDECLINABLE),
+        ("nōngentiēs", &INDECLINABLE),
+    ],
+];
+
+const KINDS: [WordKind; 4] = [
+    WordKind::Cardinal,
+    WordKind::Ordinal,
+    WordKind::Distributive,
+    WordKind::Adverb,
+];
+
+const GENDERS: [Gender; 3] = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+
+const CASES: [GrammaticalCase; 6] = [
+    GrammaticalCase::Nominative,
+    GrammaticalCase::Genitive,
+    GrammaticalCase::Dative,
+    GrammaticalCase::Accusative,
+    GrammaticalCase::Ablative,
+    GrammaticalCase::Vocative,
+];
+
+impl LatinWords {
+    /// The value of the smallest number written in words
+    pub const MIN: u32 = 1;
+    /// The value of the largest number written in words
+    pub const MAX: u32 = 999_999;
+
+    /// Creates the default words: cardinals, in the masculine nominative.
+    #[must_use]
+    pub const fn new() -> Self {
+        LatinWords {
+            kind: WordKind::Cardinal,
+            gender: Gender::Masculine,
+            case: GrammaticalCase::Nominative,
+        }
+    }
+
+    /// Sets the series of number words.
+    #[must_use]
+    pub const fn kind(self, kind: WordKind) -> Self {
+        LatinWords { kind, ..self }
+    }
+
+    /// Sets the gender the words agree with.
+    #[must_use]
+    pub const fn gender(self, gender: Gender) -> Self {
+        LatinWords { gender, ..self }
+    }
+
+    /// Sets the case the words are declined in.
+    #[must_use]
+    pub const fn case(self, case: GrammaticalCase) -> Self {
+        LatinWords { case, ..self }
+    }
+
+    /// Writes ``value`` out in these words.
+    /// Requires ``value`` to be between 1 and 999,999.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let words = LatinWords::new().case(GrammaticalCase::Ablative);
+    ///    assert_eq!("duōbus mīlibus ducentīs", words.format(2200)?);
+    ///
+    #[cfg(feature = "std")]
+    pub fn format(&self, value: u32) -> Result<String, OutOfRangeError> {
+        let mut out = String::new();
+        match self.write(value, &mut out) {
+            Ok(()) => Ok(out),
+            Err(_) => Err(OutOfRangeError),
+        }
+    }
+
+    /// Writes ``value`` out in these words to any ``fmt::Write``, without allocating.
+    ///
+    /// Returns ``fmt::Error`` if ``out`` does, or if ``value`` is out of range.
+    pub fn write<W: Write>(&self, value: u32, out: &mut W) -> fmt::Result {
+        if !(Self::MIN..=Self::MAX).contains(&value) {
+            return Err(fmt::Error);
+        }
+        let mut first = true;
+        let (thousands, rest) = (value / 1000, value % 1000);
+        if thousands > 0 {
+            match self.kind {
+                WordKind::Cardinal if thousands == 1 => {
+                    self.write_word("", MILLE, &mut first, out)?;
+                }
+                WordKind::Cardinal | WordKind::Distributive => {
+                    self.gender(Gender::Neuter)
+                        .write_below_thousand(thousands, &mut first, out)?;
+                    self.write_word("", MILIA, &mut first, out)?;
+                }
+                WordKind::Ordinal => {
+                    if thousands > 1 {
+                        self.kind(WordKind::Adverb)
+                            .write_below_thousand(thousands, &mut first, out)?;
+                    }
+                    self.write_word("", MILLESIMUS, &mut first, out)?;
+                }
+                WordKind::Adverb => {
+                    if thousands > 1 {
+                        self.write_below_thousand(thousands, &mut first, out)?;
+                    }
+                    self.write_word("", MILIES, &mut first, out)?;
+                }
+            }
+        }
+        if rest > 0 {
+            self.write_below_thousand(rest, &mut first, out)?;
+        }
+        Ok(())
+    }
+
+    /// Parses a number written in Latin words, returning its value and the first words it is
+    /// written in, trying the kinds, genders and cases in the order they are declared.
+    ///
+    /// The words must be written as ``write`` writes them, but case, macrons and the whitespace
+    /// between words are ignored, so duodevicesimus reads as well as duodēvīcēsimus.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let (value, words) = LatinWords::parse("Trium Milium")?;
+    ///    assert_eq!(value, 3000);
+    ///    assert_eq!(words, LatinWords::new().case(GrammaticalCase::Genitive));
+    ///
+    pub fn parse(s: &str) -> Result<(u32, Self), ParseNumeralError> {
+        let start = s.len() - s.trim_start().len();
+        if s.trim().is_empty() {
+            return Err(ParseNumeralError::new(ParseErrorKind::Empty, 0));
+        }
+        let mut total: u32 = 0;
+        let mut group: u32 = 0;
+        for word in s.split_whitespace() {
+            // split_whitespace returns slices of s, so the offset is the distance between them
+            let position = word.as_ptr() as usize - s.as_ptr() as usize;
+            match Self::word_value(word) {
+                Some(1000) => {
+                    total = total.saturating_add(group.max(1).saturating_mul(1000));
+                    group = 0;
+                }
+                Some(value) => group = group.saturating_add(value),
+                None => {
+                    return Err(ParseNumeralError::new(
+                        ParseErrorKind::InvalidCharacter,
+                        position,
+                    ));
+                }
+            }
+        }
+        let value = total.saturating_add(group);
+        if value > Self::MAX {
+            return Err(ParseNumeralError::new(ParseErrorKind::OutOfRange, start));
+        }
+        // Only the canonical words of one kind, gender and case are accepted
+        let mut position = start;
+        for kind in KINDS {
+            for gender in GENDERS {
+                for case in CASES {
+                    let words = LatinWords { kind, gender, case };
+                    let mut matcher = Matcher::new(s, start);
+                    if words.write(value, &mut matcher).is_ok() {
+                        if matcher.rest.trim_end().is_empty() {
+                            return Ok((value, words));
+                        }
+                        position = position.max(s.len() - matcher.rest.trim_start().len());
+                    } else {
+                        position = position.max(matcher.word);
+                    }
+                }
+            }
+        }
+        Err(ParseNumeralError::new(
+            ParseErrorKind::NonCanonical,
+            position,
+        ))
+    }
+
+    /// Helper function to write one to 999 as a sequence of words
+    fn write_below_thousand<W: Write>(
+        &self,
+        value: u32,
+        first: &mut bool,
+        out: &mut W,
+    ) -> fmt::Result {
+        let kind = self.kind as usize;
+        let (hundreds, tens, units) = (value / 100, value / 10 % 10, value % 10);
+        if hundreds > 0 {
+            self.write_word("", HUNDREDS[kind][hundreds as usize - 1], first, out)?;
+        }
+        if (1..=8).contains(&tens) && units >= 8 {
+            let prefix = SUBTRACTIVE[units as usize - 8];
+            return self.write_word(prefix, TENS[kind][tens as usize], first, out);
+        }
+        if tens == 1 && units > 0 {
+            if let Some(teen) = TEENS[kind][units as usize - 1] {
+                return self.write_word("", teen, first, out);
+            }
+            self.write_word("", UNITS[kind][units as usize - 1], first, out)?;
+            return self.write_word("", TENS[kind][0], first, out);
+        }
+        if tens > 0 {
+            self.write_word("", TENS[kind][tens as usize - 1], first, out)?;
+        }
+        if units > 0 {
+            self.write_word("", UNITS[kind][units as usize - 1], first, out)?;
+        }
+        Ok(())
+    }
+
+    /// Helper function to write a single word, declined, after a space unless it comes first
+    fn write_word<W: Write>(
+        &self,
+        prefix: &str,
+        (stem, endings): Word,
+        first: &mut bool,
+        out: &mut W,
+    ) -> fmt::Result {
+        if !*first {
+            out.write_char(' ')?;
+        }
+        *first = false;
+        out.write_str(prefix)?;
+        out.write_str(stem)?;
+        out.write_str(endings[self.gender as usize][self.case as usize])
+    }
+
+    /// Helper function for the value of a single word: 1000 for the words which count thousands,
+    /// and ``None`` for a word which is not a number word
+    fn word_value(word: &str) -> Option<u32> {
+        let matches = |words: LatinWords, prefix: &str, atom: Word| {
+            let mut matcher = Matcher::new(word, 0);
+            words
+                .write_word(prefix, atom, &mut true, &mut matcher)
+                .is_ok()
+                && matcher.rest.is_empty()
+        };
+        for kind in KINDS {
+            for gender in GENDERS {
+                for case in CASES {
+                    let words = LatinWords { kind, gender, case };
+                    if [MILLE, MILIA, MILLESIMUS, MILIES]
+                        .into_iter()
+                        .any(|atom| matches(words, "", atom))
+                    {
+                        return Some(1000);
+                    }
+                    let k = kind as usize;
+                    let atoms = UNITS[k]
+                        .iter()
+                        .zip(1..)
+                        .chain(TENS[k].iter().zip((10..).step_by(10)))
+                        .chain(HUNDREDS[k].iter().zip((100..).step_by(100)));
+                    for (&atom, value) in atoms {
+                        if matches(words, "", atom) {
+                            return Some(value);
+                        }
+                    }
+                    for (teen, value) in TEENS[k].iter().zip(11..) {
+                        if teen.is_some_and(|atom| matches(words, "", atom)) {
+                            return Some(value);
+                        }
+                    }
+                    for (prefix, less) in SUBTRACTIVE.into_iter().zip([2, 1]) {
+                        for (&atom, ten) in TENS[k][1..].iter().zip((20..).step_by(10)) {
+                            if matches(words, prefix, atom) {
+                                return Some(ten - less);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Default for LatinWords {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Helper writer which compares what is written with a text, ignoring case, macrons and the
+/// amount of whitespace between words, and fails at the first difference
+struct Matcher<'a> {
+    source: &'a str,
+    rest: &'a str,
+    /// The byte offset of the word being compared
+    word: usize,
+}
+
+impl<'a> Matcher<'a> {
+    /// Helper function to compare with ``source`` from byte offset ``start``
+    fn new(source: &'a str, start: usize) -> Self {
+        Matcher {
+            source,
+            rest: &source[start..],
+            word: start,
+        }
+    }
+
+    /// Helper function for a character without its macron, in lowercase
+    fn fold(character: char) -> char {
+        match character {
+            'ā' | 'Ā' => 'a',
+            'ē' | 'Ē' => 'e',
+            'ī' | 'Ī' => 'i',
+            'ō' | 'Ō' => 'o',
+            'ū' | 'Ū' => 'u',
+            'ȳ' | 'Ȳ' => 'y',
+            _ => character.to_ascii_lowercase(),
+        }
+    }
+}
+
+impl Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for character in s.chars() {
+            if character == ' ' {
+                let next = self.rest.trim_start();
+                if next.len() == self.rest.len() {
+                    return Err(fmt::Error);
+                }
+                self.rest = next;
+                self.word = self.source.len() - next.len();
+                continue;
+            }
+            let mut chars = self.rest.chars();
+            match chars.next() {
+                Some(next) if Self::fold(next) == Self::fold(character) => {
+                    // A macron may also be written as a combining character
+                    self.rest = chars.as_str().trim_start_matches('\u{304}');
+                }
+                _ => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RomanNumeral {
+    /// Converts a ``RomanNumeral`` to Latin words.
+    /// Requires the value to be between 1 and 999,999.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: RomanNumeral = RomanNumeral::new(18)?;
+    ///    assert_eq!("duodēvīgintī", num.format_latin(&LatinWords::new())?);
+    ///
+    #[cfg(feature = "std")]
+    pub fn format_latin(&self, words: &LatinWords) -> Result<String, OutOfRangeError> {
+        let value = u32::try_from(self.0).map_err(|_| OutOfRangeError)?;
+        words.format(value)
+    }
+
+    /// Writes a ``RomanNumeral`` in Latin words to any ``fmt::Write``, without allocating.
+    ///
+    /// Returns ``fmt::Error`` if ``out`` does, or if the value is out of range.
+    pub fn write_latin<W: Write>(&self, words: &LatinWords, out: &mut W) -> fmt::Result {
+        let value = u32::try_from(self.0).map_err(|_| fmt::Error)?;
+        words.write(value, out)
+    }
+
+    /// Parses a ``RomanNumeral`` from Latin words of any kind, gender and case, as
+    /// ``LatinWords::parse`` does.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let num: RomanNumeral = RomanNumeral::parse_latin("ter mīliēs")?;
+    ///    assert_eq!("MMM", num.to_string());
+    ///
+    pub fn parse_latin(s: &str) -> Result<Self, ParseNumeralError> {
+        LatinWords::parse(s).map(|(value, _)| RomanNumeral(u64::from(value)))
+    }
+}